@@ -0,0 +1,419 @@
+//! Provides virtual axes, which turn digital, and analog inputs into smooth values.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use wolf_engine_input::{axis::{AxisSource, VirtualAxis}, keyboard::KeyCode};
+//! #
+//! // A / D, or horizontal mouse movement, for turning left / right.
+//! let mut turn_axis = VirtualAxis::new()
+//!     .with_source(AxisSource::Keys {
+//!         negative: KeyCode::A,
+//!         positive: KeyCode::D,
+//!     })
+//!     .with_source(AxisSource::MouseX)
+//!     .with_dead_zone(0.1)
+//!     .with_sensitivity(0.05);
+//!
+//! // Feed inputs to the axis as they arrive with `turn_axis.process(&input)`, then once per
+//! // frame:
+//! let turn_speed = turn_axis.update(Duration::from_millis(16));
+//! assert_eq!(turn_speed, 0.0);
+//! ```
+
+use std::time::Duration;
+
+use crate::{keyboard::KeyCode, Input};
+
+/// A source of input for a [`VirtualAxis`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum AxisSource {
+    /// A pair of keys, which push the axis towards `-1.0`, and `1.0`.
+    Keys {
+        negative: KeyCode,
+        positive: KeyCode,
+    },
+    /// Horizontal mouse movement, from [`Input::MouseMoved`] deltas.
+    MouseX,
+    /// Vertical mouse movement, from [`Input::MouseMoved`] deltas.
+    MouseY,
+}
+
+/// Shapes the output of a [`VirtualAxis`] after the dead zone is applied.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ResponseCurve {
+    /// The output is proportional to the input.
+    Linear,
+    /// The output is the input raised to a power.  Powers greater than `1.0` give finer control
+    /// near the center, and powers less than `1.0` give finer control near the edges.
+    Power(f32),
+    /// The output eases in, and out of the center, and edges.
+    SmoothStep,
+}
+
+impl ResponseCurve {
+    /// Apply the curve to a magnitude in the range `[0.0, 1.0]`.
+    pub fn apply(&self, magnitude: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => magnitude,
+            ResponseCurve::Power(power) => magnitude.powf(*power),
+            ResponseCurve::SmoothStep => magnitude * magnitude * (3.0 - 2.0 * magnitude),
+        }
+    }
+}
+
+/// Combines digital, and analog inputs into a single value in the range `[-1.0, 1.0]`.
+///
+/// Inputs are fed to the axis through [`VirtualAxis::process()`] as they arrive, then the value
+/// is calculated once per frame by [`VirtualAxis::update()`].
+///
+/// - Digital sources (key pairs) move the axis towards `-1.0`, or `1.0` at the smoothing speed,
+///   and back towards `0.0` at the gravity speed.  Snap makes the axis jump to `0.0` when the
+///   opposite direction is pressed.
+/// - Analog sources (mouse deltas) are scaled by the sensitivity, and added to the digital value.
+/// - The dead zone, and response curve are applied to the combined value.
+#[derive(Debug, Clone)]
+pub struct VirtualAxis {
+    sources: Vec<AxisSource>,
+    dead_zone: f32,
+    smoothing: f32,
+    gravity: f32,
+    is_snapping: bool,
+    sensitivity: f32,
+    response_curve: ResponseCurve,
+
+    held_keys: Vec<KeyCode>,
+    analog_delta: f32,
+    digital_value: f32,
+    value: f32,
+}
+
+impl VirtualAxis {
+    /// Create an axis with no sources, no dead zone, instant response, and a linear curve.
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            dead_zone: 0.0,
+            smoothing: f32::INFINITY,
+            gravity: f32::INFINITY,
+            is_snapping: false,
+            sensitivity: 1.0,
+            response_curve: ResponseCurve::Linear,
+
+            held_keys: Vec::new(),
+            analog_delta: 0.0,
+            digital_value: 0.0,
+            value: 0.0,
+        }
+    }
+
+    /// Add a source of input.
+    pub fn with_source(mut self, source: AxisSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Set the dead zone, in the range `[0.0, 1.0)`.
+    ///
+    /// Values with a smaller magnitude than the dead zone are reported as `0.0`, and the rest of
+    /// the range is rescaled, so the output still reaches `1.0`.
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Set the speed, in units per second, digital sources move the axis towards `-1.0`, or
+    /// `1.0`.
+    ///
+    /// The default is `f32::INFINITY`, which moves the axis instantly.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Set the speed, in units per second, the axis falls back to `0.0` when no digital source is
+    /// held.
+    ///
+    /// The default is `f32::INFINITY`, which moves the axis instantly.
+    pub fn with_gravity(mut self, gravity: f32) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Set whether the axis should jump to `0.0` when the opposite direction is pressed.
+    pub fn with_snap(mut self, is_snapping: bool) -> Self {
+        self.is_snapping = is_snapping;
+        self
+    }
+
+    /// Set the multiplier used to convert analog deltas into axis units.
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Set the response curve.
+    pub fn with_response_curve(mut self, response_curve: ResponseCurve) -> Self {
+        self.response_curve = response_curve;
+        self
+    }
+
+    /// Get the value calculated by the last [`VirtualAxis::update()`].
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Update the held keys, and accumulated analog deltas from an input.
+    pub fn process(&mut self, input: &Input) {
+        match input {
+            Input::KeyPressed { key, .. } => {
+                if let Some(keycode) = key.keycode {
+                    if self.is_bound(keycode) && !self.held_keys.contains(&keycode) {
+                        self.held_keys.push(keycode);
+                    }
+                }
+            }
+            Input::KeyReleased { key, .. } => {
+                if let Some(keycode) = key.keycode {
+                    self.held_keys.retain(|held| *held != keycode);
+                }
+            }
+            Input::MouseMoved { delta_x, delta_y } => {
+                for source in &self.sources {
+                    match source {
+                        AxisSource::MouseX => self.analog_delta += delta_x,
+                        AxisSource::MouseY => self.analog_delta += delta_y,
+                        _ => (),
+                    }
+                }
+            }
+            Input::FocusLost => self.held_keys.clear(),
+            _ => (),
+        }
+    }
+
+    /// Calculate the value for a frame which took `delta_time`, and return it.
+    ///
+    /// Analog deltas are consumed by the update, so this should be called exactly once per frame.
+    pub fn update(&mut self, delta_time: Duration) -> f32 {
+        self.update_digital_value(delta_time.as_secs_f32());
+        let analog_value = self.analog_delta * self.sensitivity;
+        self.analog_delta = 0.0;
+
+        let raw_value = (self.digital_value + analog_value).clamp(-1.0, 1.0);
+        let magnitude = raw_value.abs();
+        self.value = if magnitude <= self.dead_zone {
+            0.0
+        } else {
+            let magnitude = (magnitude - self.dead_zone) / (1.0 - self.dead_zone);
+            self.response_curve.apply(magnitude).copysign(raw_value)
+        };
+        self.value
+    }
+
+    fn update_digital_value(&mut self, delta_time: f32) {
+        let target = self.digital_target();
+        if self.is_snapping && target != 0.0 && self.digital_value * target < 0.0 {
+            self.digital_value = 0.0;
+        }
+
+        let speed = if target == 0.0 {
+            self.gravity
+        } else {
+            self.smoothing
+        };
+        let max_step = if speed.is_infinite() {
+            f32::INFINITY
+        } else {
+            speed * delta_time
+        };
+        let difference = target - self.digital_value;
+        self.digital_value = if difference.abs() <= max_step {
+            target
+        } else {
+            self.digital_value + max_step.copysign(difference)
+        };
+    }
+
+    fn digital_target(&self) -> f32 {
+        self.sources
+            .iter()
+            .map(|source| match source {
+                AxisSource::Keys { negative, positive } => {
+                    let is_held = |keycode| self.held_keys.contains(keycode) as i32 as f32;
+                    is_held(positive) - is_held(negative)
+                }
+                _ => 0.0,
+            })
+            .sum::<f32>()
+            .clamp(-1.0, 1.0)
+    }
+
+    fn is_bound(&self, keycode: KeyCode) -> bool {
+        self.sources.iter().any(|source| match source {
+            AxisSource::Keys { negative, positive } => *negative == keycode || *positive == keycode,
+            _ => false,
+        })
+    }
+}
+
+impl Default for VirtualAxis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod virtual_axis_tests {
+    use test_case::test_case;
+
+    use crate::keyboard::{Key, Modifiers};
+
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    fn key(keycode: KeyCode) -> Key {
+        Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: None,
+        }
+    }
+
+    fn press(keycode: KeyCode) -> Input {
+        Input::KeyPressed {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+            is_repeat: false,
+        }
+    }
+
+    fn release(keycode: KeyCode) -> Input {
+        Input::KeyReleased {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    fn key_axis() -> VirtualAxis {
+        VirtualAxis::new().with_source(AxisSource::Keys {
+            negative: KeyCode::A,
+            positive: KeyCode::D,
+        })
+    }
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.0001,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn should_respond_instantly_by_default() {
+        let mut axis = key_axis();
+
+        axis.process(&press(KeyCode::D));
+        assert_eq!(axis.update(FRAME), 1.0);
+        axis.process(&press(KeyCode::A));
+        assert_eq!(axis.update(FRAME), 0.0, "Opposite keys should cancel out");
+        axis.process(&release(KeyCode::D));
+        assert_eq!(axis.update(FRAME), -1.0);
+        axis.process(&release(KeyCode::A));
+        assert_eq!(axis.update(FRAME), 0.0);
+    }
+
+    #[test]
+    fn should_smooth_digital_input() {
+        let mut axis = key_axis().with_smoothing(2.0).with_gravity(5.0);
+
+        axis.process(&press(KeyCode::D));
+        assert_near(axis.update(FRAME), 0.2);
+        assert_near(axis.update(FRAME), 0.4);
+        for _ in 0..10 {
+            axis.update(FRAME);
+        }
+        assert_near(axis.value(), 1.0);
+
+        axis.process(&release(KeyCode::D));
+        assert_near(axis.update(FRAME), 0.5);
+        assert_near(axis.update(FRAME), 0.0);
+    }
+
+    #[test]
+    fn should_snap_to_zero_when_reversing() {
+        let mut axis = key_axis().with_smoothing(2.0).with_snap(true);
+
+        axis.process(&press(KeyCode::D));
+        for _ in 0..10 {
+            axis.update(FRAME);
+        }
+        axis.process(&release(KeyCode::D));
+        axis.process(&press(KeyCode::A));
+
+        assert_near(axis.update(FRAME), -0.2);
+    }
+
+    #[test]
+    fn should_scale_and_clamp_mouse_deltas() {
+        let mut axis = VirtualAxis::new()
+            .with_source(AxisSource::MouseX)
+            .with_sensitivity(0.01);
+
+        axis.process(&Input::MouseMoved {
+            delta_x: 20.0,
+            delta_y: 500.0,
+        });
+        axis.process(&Input::MouseMoved {
+            delta_x: 30.0,
+            delta_y: 500.0,
+        });
+        assert_near(axis.update(FRAME), 0.5);
+        assert_eq!(axis.update(FRAME), 0.0, "Deltas should be consumed");
+
+        axis.process(&Input::MouseMoved {
+            delta_x: -1000.0,
+            delta_y: 0.0,
+        });
+        assert_eq!(axis.update(FRAME), -1.0);
+    }
+
+    #[test_case(ResponseCurve::Linear, 0.5)]
+    #[test_case(ResponseCurve::Power(2.0), 0.25)]
+    #[test_case(ResponseCurve::SmoothStep, 0.5)]
+    fn should_apply_dead_zone_and_response_curve(response_curve: ResponseCurve, expected: f32) {
+        let mut axis = VirtualAxis::new()
+            .with_source(AxisSource::MouseY)
+            .with_dead_zone(0.2)
+            .with_response_curve(response_curve);
+
+        axis.process(&Input::MouseMoved {
+            delta_x: 0.0,
+            delta_y: -0.1,
+        });
+        assert_eq!(
+            axis.update(FRAME),
+            0.0,
+            "Values in the dead zone should be 0"
+        );
+
+        axis.process(&Input::MouseMoved {
+            delta_x: 0.0,
+            delta_y: -0.6,
+        });
+        assert_near(axis.update(FRAME), -expected);
+    }
+
+    #[test]
+    fn should_release_keys_on_focus_loss() {
+        let mut axis = key_axis();
+
+        axis.process(&press(KeyCode::D));
+        axis.process(&Input::FocusLost);
+
+        assert_eq!(axis.update(FRAME), 0.0);
+    }
+}
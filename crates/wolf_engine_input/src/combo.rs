@@ -0,0 +1,565 @@
+//! Provides combo, chord, and sequence detection.
+//!
+//! Combos are defined declaratively as a series of [`ComboStep`]s, each of which is a set of
+//! buttons that must be held at the same time.  A [`ComboMatcher`] watches a stream of
+//! [`Input`]s, and reports each combo as it is recognized.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use wolf_engine_input::{
+//! #     combo::{Combo, ComboMatcher, ComboStep},
+//! #     keyboard::{Key, KeyCode, Modifiers},
+//! #     Input,
+//! # };
+//! #
+//! let mut combo_matcher = ComboMatcher::default()
+//!     // Down, down-forward, forward + punch, all within 300ms.
+//!     .with_combo(Combo::sequence(
+//!         "hadoken",
+//!         [
+//!             ComboStep::from(KeyCode::S),
+//!             ComboStep::new([KeyCode::S, KeyCode::D]),
+//!             ComboStep::new([KeyCode::D, KeyCode::J]),
+//!         ],
+//!         Duration::from_millis(300),
+//!     ))
+//!     // Ctrl + Shift + P.
+//!     .with_combo(Combo::chord(
+//!         "command_palette",
+//!         ComboStep::from(KeyCode::P).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT),
+//!     ));
+//!
+//! // Inputs are fed in along with the time they happened.
+//! let input = Input::KeyPressed {
+//!     key: Key { scancode: 25, keycode: Some(KeyCode::P), logical_key: None },
+//!     modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+//!     is_repeat: false,
+//! };
+//! let mut recognized = Vec::new();
+//! combo_matcher.process(&input, Duration::from_secs(1), |name| recognized.push(name.to_string()));
+//!
+//! assert_eq!(recognized, vec!["command_palette"]);
+//! ```
+
+use std::{fmt, str::FromStr, time::Duration};
+
+use crate::{
+    keyboard::{KeyCode, Modifiers},
+    mouse::MouseButton,
+    Input, ParseInputError,
+};
+
+/// A button which can be used in a combo.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComboButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl From<KeyCode> for ComboButton {
+    fn from(keycode: KeyCode) -> Self {
+        Self::Key(keycode)
+    }
+}
+
+impl From<MouseButton> for ComboButton {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+/// Combo buttons are written as either a [`KeyCode`], or a [`MouseButton`], such as `"S"`, or
+/// `"Mouse:Left"`.
+impl fmt::Display for ComboButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComboButton::Key(keycode) => keycode.fmt(f),
+            ComboButton::Mouse(button) => button.fmt(f),
+        }
+    }
+}
+
+impl FromStr for ComboButton {
+    type Err = ParseInputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.parse::<MouseButton>()
+            .map(ComboButton::Mouse)
+            .or_else(|_| text.parse::<KeyCode>().map(ComboButton::Key))
+            .map_err(|_| ParseInputError::new("button", text))
+    }
+}
+
+/// A single step of a combo.
+///
+/// A step is made up of one or more buttons which must all be held at the same time, and the
+/// exact set of modifier keys which must be held along with them.  The step is completed when the
+/// last of its buttons is pressed.
+///
+/// Modifier keys can also be used as buttons, such as in `"LeftShift+A"`.  The modifiers they
+/// set while held are ignored when checking the step's modifiers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComboStep {
+    buttons: Vec<ComboButton>,
+    modifiers: Modifiers,
+}
+
+impl ComboStep {
+    /// Create a step from a set of buttons which must be held at the same time.
+    pub fn new<B: Into<ComboButton>, I: IntoIterator<Item = B>>(buttons: I) -> Self {
+        Self {
+            buttons: buttons.into_iter().map(Into::into).collect(),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Set the modifier keys which must be held for the step to be completed.
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Get the buttons which must be held for the step to be completed.
+    pub fn buttons(&self) -> &[ComboButton] {
+        &self.buttons
+    }
+
+    /// Get the modifier keys which must be held for the step to be completed.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    fn is_completed_by(
+        &self,
+        pressed: ComboButton,
+        held: &[ComboButton],
+        modifiers: Modifiers,
+    ) -> bool {
+        let button_modifiers = self
+            .buttons
+            .iter()
+            .filter_map(|button| match button {
+                ComboButton::Key(keycode) => Modifiers::from_keycode(*keycode),
+                ComboButton::Mouse(_) => None,
+            })
+            .fold(Modifiers::empty(), |all, modifier| all | modifier);
+        self.modifiers | button_modifiers == modifiers | button_modifiers
+            && self.buttons.contains(&pressed)
+            && self.buttons.iter().all(|button| held.contains(button))
+    }
+}
+
+impl From<KeyCode> for ComboStep {
+    fn from(keycode: KeyCode) -> Self {
+        Self::new([keycode])
+    }
+}
+
+impl From<MouseButton> for ComboStep {
+    fn from(button: MouseButton) -> Self {
+        Self::new([button])
+    }
+}
+
+/// Steps are written as their modifiers, followed by their buttons, joined by `+`, such as
+/// `"Ctrl+Shift+S"`, `"Mouse:Left"`, or `"S+D"`.
+impl fmt::Display for ComboStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.modifiers.is_empty() {
+            parts.push(self.modifiers.to_string());
+        }
+        parts.extend(self.buttons.iter().map(ToString::to_string));
+        f.pad(&parts.join("+"))
+    }
+}
+
+/// Parses a step from its canonical form.  Modifier, and button names are not case-sensitive.
+impl FromStr for ComboStep {
+    type Err = ParseInputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut step = ComboStep::new(Vec::<ComboButton>::new());
+        for part in text.split('+').map(str::trim) {
+            match Modifiers::from_key_name(part) {
+                Some(modifier) => step.modifiers |= modifier,
+                None => step.buttons.push(
+                    part.parse()
+                        .map_err(|_| ParseInputError::new("combo step", text))?,
+                ),
+            }
+        }
+        if step.buttons.is_empty() {
+            return Err(ParseInputError::new("combo step", text));
+        }
+        Ok(step)
+    }
+}
+
+/// A named combo definition.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Combo {
+    name: String,
+    steps: Vec<ComboStep>,
+    time_window: Duration,
+}
+
+impl Combo {
+    /// Create a chord: a single step, such as Ctrl + Shift + P.
+    pub fn chord<S: Into<ComboStep>>(name: &str, step: S) -> Self {
+        Self::sequence(name, [step], Duration::ZERO)
+    }
+
+    /// Create a sequence of steps, which must all be completed, in order, within the time window.
+    ///
+    /// Other inputs between steps are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no steps.
+    pub fn sequence<S: Into<ComboStep>, I: IntoIterator<Item = S>>(
+        name: &str,
+        steps: I,
+        time_window: Duration,
+    ) -> Self {
+        let steps: Vec<ComboStep> = steps.into_iter().map(Into::into).collect();
+        assert!(!steps.is_empty(), "A combo must have at least one step");
+        Self {
+            name: name.to_string(),
+            steps,
+            time_window,
+        }
+    }
+
+    /// Get the name of the combo.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A partially-completed combo.
+#[derive(Debug, Clone, Copy)]
+struct ComboProgress {
+    next_step: usize,
+    start_time: Duration,
+}
+
+/// Recognizes [`Combo`]s from a stream of [`Input`]s.
+#[derive(Debug, Default, Clone)]
+pub struct ComboMatcher {
+    combos: Vec<(Combo, Vec<ComboProgress>)>,
+    held: Vec<ComboButton>,
+    modifiers: Modifiers,
+}
+
+impl ComboMatcher {
+    /// Add a combo to be recognized.
+    pub fn with_combo(mut self, combo: Combo) -> Self {
+        self.add_combo(combo);
+        self
+    }
+
+    /// Add a combo to be recognized.
+    pub fn add_combo(&mut self, combo: Combo) {
+        self.combos.push((combo, Vec::new()));
+    }
+
+    /// Forget all held buttons, and partially-completed combos.
+    pub fn reset(&mut self) {
+        self.held.clear();
+        self.modifiers = Modifiers::empty();
+        for (_, progress) in &mut self.combos {
+            progress.clear();
+        }
+    }
+
+    /// Process an input which happened at `time`, and pass the name of each combo it completes
+    /// on to the handler.
+    ///
+    /// The time can be measured from any starting point, but it must never go backwards.
+    pub fn process<F: FnMut(&str)>(&mut self, input: &Input, time: Duration, mut handler: F) {
+        match input {
+            Input::KeyPressed {
+                key,
+                modifiers,
+                is_repeat: false,
+            } => {
+                self.modifiers = *modifiers;
+                if let Some(keycode) = key.keycode {
+                    self.press(keycode.into(), time, &mut handler);
+                }
+            }
+            Input::KeyReleased { key, modifiers } => {
+                self.modifiers = *modifiers;
+                if let Some(keycode) = key.keycode {
+                    self.release(keycode.into());
+                }
+            }
+            Input::ModifiersChanged { modifiers } => self.modifiers = *modifiers,
+            Input::MouseButtonPressed { button } => self.press((*button).into(), time, handler),
+            Input::MouseButtonReleased { button } => self.release((*button).into()),
+            Input::FocusLost => self.reset(),
+            _ => (),
+        }
+    }
+
+    fn press<F: FnMut(&str)>(&mut self, button: ComboButton, time: Duration, mut handler: F) {
+        if !self.held.contains(&button) {
+            self.held.push(button);
+        }
+
+        for (combo, progress) in &mut self.combos {
+            progress
+                .retain(|progress| time.saturating_sub(progress.start_time) <= combo.time_window);
+            progress.push(ComboProgress {
+                next_step: 0,
+                start_time: time,
+            });
+
+            let mut is_completed = false;
+            progress.retain_mut(|progress| {
+                let step = &combo.steps[progress.next_step];
+                if step.is_completed_by(button, &self.held, self.modifiers) {
+                    progress.next_step += 1;
+                }
+                if progress.next_step == combo.steps.len() {
+                    is_completed = true;
+                }
+                !is_completed && progress.next_step > 0
+            });
+
+            if is_completed {
+                progress.clear();
+                handler(&combo.name);
+            }
+        }
+    }
+
+    fn release(&mut self, button: ComboButton) {
+        self.held.retain(|held| *held != button);
+    }
+}
+
+#[cfg(test)]
+mod combo_tests {
+    use crate::keyboard::Key;
+
+    use super::*;
+
+    fn key(keycode: KeyCode) -> Key {
+        Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: None,
+        }
+    }
+
+    fn press(keycode: KeyCode, modifiers: Modifiers) -> Input {
+        Input::KeyPressed {
+            key: key(keycode),
+            modifiers,
+            is_repeat: false,
+        }
+    }
+
+    fn release(keycode: KeyCode) -> Input {
+        Input::KeyReleased {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    fn run(combo_matcher: &mut ComboMatcher, inputs: &[(u64, Input)]) -> Vec<String> {
+        let mut recognized = Vec::new();
+        for (millis, input) in inputs {
+            combo_matcher.process(input, Duration::from_millis(*millis), |name| {
+                recognized.push(name.to_string())
+            });
+        }
+        recognized
+    }
+
+    fn hadoken() -> ComboMatcher {
+        ComboMatcher::default().with_combo(Combo::sequence(
+            "hadoken",
+            [
+                ComboStep::from(KeyCode::DownArrow),
+                ComboStep::new([KeyCode::DownArrow, KeyCode::RightArrow]),
+                ComboStep::new([
+                    ComboButton::Key(KeyCode::RightArrow),
+                    ComboButton::Mouse(MouseButton::Left),
+                ]),
+            ],
+            Duration::from_millis(300),
+        ))
+    }
+
+    #[test]
+    fn should_recognize_sequences() {
+        let mut combo_matcher = hadoken();
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::DownArrow, Modifiers::empty())),
+                (50, press(KeyCode::RightArrow, Modifiers::empty())),
+                (100, release(KeyCode::DownArrow)),
+                (
+                    150,
+                    Input::MouseButtonPressed {
+                        button: MouseButton::Left,
+                    },
+                ),
+            ],
+        );
+
+        assert_eq!(recognized, vec!["hadoken"]);
+    }
+
+    #[test]
+    fn should_not_recognize_sequences_outside_the_time_window() {
+        let mut combo_matcher = hadoken();
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::DownArrow, Modifiers::empty())),
+                (50, press(KeyCode::RightArrow, Modifiers::empty())),
+                (
+                    400,
+                    Input::MouseButtonPressed {
+                        button: MouseButton::Left,
+                    },
+                ),
+            ],
+        );
+
+        assert!(recognized.is_empty());
+    }
+
+    #[test]
+    fn should_not_recognize_steps_out_of_order() {
+        let mut combo_matcher = hadoken();
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::RightArrow, Modifiers::empty())),
+                (
+                    50,
+                    Input::MouseButtonPressed {
+                        button: MouseButton::Left,
+                    },
+                ),
+                (100, press(KeyCode::DownArrow, Modifiers::empty())),
+            ],
+        );
+
+        assert!(recognized.is_empty());
+    }
+
+    #[test]
+    fn should_recognize_chords_with_exact_modifiers() {
+        let mut combo_matcher = ComboMatcher::default().with_combo(Combo::chord(
+            "command_palette",
+            ComboStep::from(KeyCode::P).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT),
+        ));
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::P, Modifiers::CONTROL)),
+                (10, release(KeyCode::P)),
+                (
+                    1000,
+                    press(KeyCode::P, Modifiers::CONTROL | Modifiers::SHIFT),
+                ),
+                (1010, release(KeyCode::P)),
+                (
+                    2000,
+                    press(
+                        KeyCode::P,
+                        Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT,
+                    ),
+                ),
+            ],
+        );
+
+        assert_eq!(recognized, vec!["command_palette"]);
+    }
+
+    #[test]
+    fn should_recognize_steps_with_modifier_keys() {
+        let mut combo_matcher = ComboMatcher::default().with_combo(Combo::chord(
+            "sprint",
+            ComboStep::new([KeyCode::LeftShift, KeyCode::W]),
+        ));
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::W, Modifiers::CONTROL)),
+                (10, release(KeyCode::W)),
+                (1000, press(KeyCode::LeftShift, Modifiers::empty())),
+                (1010, press(KeyCode::W, Modifiers::SHIFT)),
+            ],
+        );
+
+        assert_eq!(recognized, vec!["sprint"]);
+    }
+
+    #[test]
+    fn should_forget_held_buttons_on_focus_loss() {
+        let mut combo_matcher = ComboMatcher::default().with_combo(Combo::chord(
+            "chord",
+            ComboStep::new([KeyCode::A, KeyCode::B]),
+        ));
+
+        let recognized = run(
+            &mut combo_matcher,
+            &[
+                (0, press(KeyCode::A, Modifiers::empty())),
+                (10, Input::FocusLost),
+                (20, press(KeyCode::B, Modifiers::empty())),
+            ],
+        );
+
+        assert!(recognized.is_empty());
+    }
+
+    #[test]
+    fn should_round_trip_steps_through_strings() {
+        let steps = [
+            (
+                ComboStep::from(KeyCode::S).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT),
+                "Ctrl+Shift+S",
+            ),
+            (ComboStep::from(MouseButton::Left), "Mouse:Left"),
+            (
+                ComboStep::new([KeyCode::S, KeyCode::D]).with_modifiers(Modifiers::ALT),
+                "Alt+S+D",
+            ),
+            (
+                ComboStep::new([
+                    ComboButton::Key(KeyCode::LeftAlt),
+                    MouseButton::Other(4).into(),
+                ]),
+                "LeftAlt+Mouse:4",
+            ),
+        ];
+
+        for (step, text) in steps {
+            assert_eq!(step.to_string(), text);
+            assert_eq!(text.parse::<ComboStep>(), Ok(step));
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_steps() {
+        assert!("".parse::<ComboStep>().is_err());
+        assert!("Ctrl+Shift".parse::<ComboStep>().is_err());
+        assert!("Ctrl+Nope".parse::<ComboStep>().is_err());
+    }
+}
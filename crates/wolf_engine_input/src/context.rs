@@ -0,0 +1,453 @@
+//! Provides input contexts, which route inputs through layers such as UI, console, and gameplay.
+//!
+//! An [`InputContextStack`] holds a stack of named [`InputContext`]s.  Each [`Input`] is routed
+//! from the top of the stack down, and stops at the first context which consumes it.  Contexts
+//! can be pushed, and popped at runtime, such as when a pause menu is opened, or closed.
+//!
+//! # Held Buttons
+//!
+//! A key, or mouse button release is always routed to the contexts which received the press,
+//! even if the stack has changed since.  This keeps buttons from getting "stuck" in a context
+//! which was covered up while they were held.
+//!
+//! Held buttons are forgotten on [`Input::FocusLost`], because their releases won't be received.
+//! Afterwards, pressing them again routes them through the current stack as usual.
+//!
+//! # State Changes
+//!
+//! Inputs which describe a change of state, rather than an action, are routed to every context,
+//! and are never consumed.  These are [`Input::ModifiersChanged`], [`Input::CursorEntered`],
+//! [`Input::CursorLeft`], [`Input::FocusGained`], and [`Input::FocusLost`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use wolf_engine_input::{
+//! #     context::{InputContext, InputContextStack, InputFilter},
+//! #     keyboard::{Key, KeyCode, Modifiers},
+//! #     Input,
+//! # };
+//! #
+//! let mut contexts = InputContextStack::default();
+//! contexts.push(InputContext::new("gameplay").with_consumed(InputFilter::All));
+//!
+//! // Opening the pause menu covers gameplay, and takes over the Escape key.
+//! contexts.push(
+//!     InputContext::new("pause_menu").with_consumed(InputFilter::Button(KeyCode::Escape.into())),
+//! );
+//!
+//! let input = Input::KeyPressed {
+//!     key: Key { scancode: 1, keycode: Some(KeyCode::Escape), logical_key: None },
+//!     modifiers: Modifiers::empty(),
+//!     is_repeat: false,
+//! };
+//! let mut received = Vec::new();
+//! contexts.dispatch(&input, |context, _| received.push(context.to_string()));
+//!
+//! assert_eq!(received, vec!["pause_menu"]);
+//! ```
+
+use crate::{
+    combo::ComboButton,
+    keyboard::{Key, KeyCode},
+    mouse::MouseButton,
+    Input,
+};
+
+/// Describes a set of inputs an [`InputContext`] consumes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum InputFilter {
+    /// All inputs.
+    All,
+    /// Key presses, and releases.
+    Keyboard,
+    /// Mouse motion, buttons, and scrolling.
+    Mouse,
+    /// Touch inputs.
+    Touch,
+    /// Presses, and releases of a single key, or mouse button.
+    Button(ComboButton),
+}
+
+impl InputFilter {
+    fn matches(&self, input: &Input) -> bool {
+        match self {
+            InputFilter::All => true,
+            InputFilter::Keyboard => {
+                matches!(input, Input::KeyPressed { .. } | Input::KeyReleased { .. })
+            }
+            InputFilter::Mouse => matches!(
+                input,
+                Input::MouseMovedTo { .. }
+                    | Input::MouseMoved { .. }
+                    | Input::MouseButtonPressed { .. }
+                    | Input::MouseButtonReleased { .. }
+                    | Input::MouseScrolled { .. }
+            ),
+            InputFilter::Touch => matches!(
+                input,
+                Input::TouchStarted { .. }
+                    | Input::TouchMoved { .. }
+                    | Input::TouchEnded { .. }
+                    | Input::TouchCancelled { .. }
+            ),
+            InputFilter::Button(button) => match input {
+                Input::KeyPressed { key, .. } | Input::KeyReleased { key, .. } => {
+                    key.keycode.map(ComboButton::Key) == Some(*button)
+                }
+                Input::MouseButtonPressed { button: pressed }
+                | Input::MouseButtonReleased { button: pressed } => {
+                    ComboButton::Mouse(*pressed) == *button
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// A named layer of input handling, such as UI, console, or gameplay.
+///
+/// A context receives all inputs which reach it, but only stops the ones matching its
+/// [`InputFilter`]s from reaching the contexts below it.  A context without any filters is
+/// "transparent," and passes everything on.
+#[derive(Debug, PartialEq, Clone)]
+pub struct InputContext {
+    name: String,
+    consumed: Vec<InputFilter>,
+}
+
+impl InputContext {
+    /// Create a new context, which doesn't consume any inputs.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            consumed: Vec::new(),
+        }
+    }
+
+    /// Add a set of inputs the context consumes.
+    pub fn with_consumed(mut self, filter: InputFilter) -> Self {
+        self.consumed.push(filter);
+        self
+    }
+
+    /// Get the name of the context.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns true if the context consumes the input.
+    pub fn consumes(&self, input: &Input) -> bool {
+        !is_state_change(input) && self.consumed.iter().any(|filter| filter.matches(input))
+    }
+}
+
+/// Routes inputs through a stack of [`InputContext`]s.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Debug, Default, Clone)]
+pub struct InputContextStack {
+    contexts: Vec<InputContext>,
+    held_buttons: Vec<(HeldButton, Vec<String>)>,
+}
+
+impl InputContextStack {
+    /// Push a context onto the top of the stack.
+    pub fn push(&mut self, context: InputContext) {
+        self.contexts.push(context);
+    }
+
+    /// Remove, and return the context on the top of the stack.
+    pub fn pop(&mut self) -> Option<InputContext> {
+        self.contexts.pop()
+    }
+
+    /// Remove, and return the top-most context with the given name.
+    pub fn remove(&mut self, name: &str) -> Option<InputContext> {
+        let index = self
+            .contexts
+            .iter()
+            .rposition(|context| context.name == name)?;
+        Some(self.contexts.remove(index))
+    }
+
+    /// Get the context on the top of the stack.
+    pub fn top(&self) -> Option<&InputContext> {
+        self.contexts.last()
+    }
+
+    /// Returns true if a context with the given name is on the stack.
+    pub fn contains(&self, name: &str) -> bool {
+        self.contexts.iter().any(|context| context.name == name)
+    }
+
+    /// Route an input through the stack.
+    ///
+    /// The `handler` is called with the name of each context the input reaches, from the top of
+    /// the stack down.
+    pub fn dispatch<F: FnMut(&str, &Input)>(&mut self, input: &Input, handler: F) {
+        if let Some(button) = HeldButton::released_by(input) {
+            if let Some(index) = self
+                .held_buttons
+                .iter()
+                .position(|(held_button, _)| *held_button == button)
+            {
+                let (_, receivers) = self.held_buttons.remove(index);
+                self.dispatch_to(&receivers, input, handler);
+                return;
+            }
+        }
+        if let Some(button) = HeldButton::pressed_by(input) {
+            if let Some((_, receivers)) = self
+                .held_buttons
+                .iter()
+                .find(|(held_button, _)| *held_button == button)
+            {
+                // Repeated presses go to the same contexts as the original press.
+                let receivers = receivers.clone();
+                self.dispatch_to(&receivers, input, handler);
+                return;
+            }
+            let receivers = self.route(input, handler);
+            self.held_buttons.push((button, receivers));
+            return;
+        }
+        if let Input::FocusLost = input {
+            self.held_buttons.clear();
+        }
+        self.route(input, handler);
+    }
+
+    fn route<F: FnMut(&str, &Input)>(&self, input: &Input, mut handler: F) -> Vec<String> {
+        let mut receivers = Vec::new();
+        for context in self.contexts.iter().rev() {
+            handler(&context.name, input);
+            receivers.push(context.name.clone());
+            if context.consumes(input) {
+                break;
+            }
+        }
+        receivers
+    }
+
+    fn dispatch_to<F: FnMut(&str, &Input)>(
+        &self,
+        receivers: &[String],
+        input: &Input,
+        mut handler: F,
+    ) {
+        self.contexts
+            .iter()
+            .rev()
+            .filter(|context| receivers.contains(&context.name))
+            .for_each(|context| handler(&context.name, input));
+    }
+}
+
+/// Identifies a held key, or mouse button.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum HeldButton {
+    Key {
+        scancode: u32,
+        keycode: Option<KeyCode>,
+    },
+    Mouse(MouseButton),
+}
+
+impl HeldButton {
+    fn from_key(key: &Key) -> Self {
+        Self::Key {
+            scancode: key.scancode,
+            keycode: key.keycode,
+        }
+    }
+
+    fn pressed_by(input: &Input) -> Option<Self> {
+        match input {
+            Input::KeyPressed { key, .. } => Some(Self::from_key(key)),
+            Input::MouseButtonPressed { button } => Some(Self::Mouse(*button)),
+            _ => None,
+        }
+    }
+
+    fn released_by(input: &Input) -> Option<Self> {
+        match input {
+            Input::KeyReleased { key, .. } => Some(Self::from_key(key)),
+            Input::MouseButtonReleased { button } => Some(Self::Mouse(*button)),
+            _ => None,
+        }
+    }
+}
+
+fn is_state_change(input: &Input) -> bool {
+    matches!(
+        input,
+        Input::ModifiersChanged { .. }
+            | Input::CursorEntered
+            | Input::CursorLeft
+            | Input::FocusGained
+            | Input::FocusLost
+    )
+}
+
+#[cfg(test)]
+mod input_context_tests {
+    use crate::keyboard::Modifiers;
+
+    use super::*;
+
+    fn key(keycode: KeyCode) -> Key {
+        Key {
+            scancode: keycode as u32,
+            keycode: Some(keycode),
+            logical_key: None,
+        }
+    }
+
+    fn press(keycode: KeyCode) -> Input {
+        Input::KeyPressed {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+            is_repeat: false,
+        }
+    }
+
+    fn release(keycode: KeyCode) -> Input {
+        Input::KeyReleased {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    fn dispatch(contexts: &mut InputContextStack, input: Input) -> Vec<String> {
+        let mut received = Vec::new();
+        contexts.dispatch(&input, |context, _| received.push(context.to_string()));
+        received
+    }
+
+    fn game_stack() -> InputContextStack {
+        let mut contexts = InputContextStack::default();
+        contexts.push(InputContext::new("gameplay").with_consumed(InputFilter::All));
+        contexts.push(InputContext::new("debug_overlay"));
+        contexts
+    }
+
+    #[test]
+    fn should_route_inputs_top_down_until_consumed() {
+        let mut contexts = game_stack();
+        contexts.push(
+            InputContext::new("console")
+                .with_consumed(InputFilter::Keyboard)
+                .with_consumed(InputFilter::Button(MouseButton::Left.into())),
+        );
+
+        assert_eq!(dispatch(&mut contexts, press(KeyCode::W)), vec!["console"]);
+        assert_eq!(
+            dispatch(
+                &mut contexts,
+                Input::MouseButtonPressed {
+                    button: MouseButton::Right
+                }
+            ),
+            vec!["console", "debug_overlay", "gameplay"]
+        );
+        assert_eq!(
+            dispatch(
+                &mut contexts,
+                Input::MouseButtonPressed {
+                    button: MouseButton::Left
+                }
+            ),
+            vec!["console"]
+        );
+    }
+
+    #[test]
+    fn should_push_and_pop_contexts() {
+        let mut contexts = game_stack();
+        contexts.push(
+            InputContext::new("pause_menu")
+                .with_consumed(InputFilter::Button(KeyCode::Escape.into())),
+        );
+
+        assert_eq!(
+            dispatch(&mut contexts, press(KeyCode::Escape)),
+            vec!["pause_menu"]
+        );
+        assert_eq!(contexts.top().map(InputContext::name), Some("pause_menu"));
+        assert_eq!(contexts.pop().unwrap().name(), "pause_menu");
+        assert!(!contexts.contains("pause_menu"));
+        assert_eq!(
+            dispatch(&mut contexts, press(KeyCode::Enter)),
+            vec!["debug_overlay", "gameplay"]
+        );
+        assert_eq!(contexts.remove("gameplay").unwrap().name(), "gameplay");
+        assert_eq!(contexts.remove("gameplay"), None);
+    }
+
+    #[test]
+    fn should_release_buttons_to_the_contexts_which_received_the_press() {
+        let mut contexts = game_stack();
+
+        dispatch(&mut contexts, press(KeyCode::W));
+        contexts.push(InputContext::new("pause_menu").with_consumed(InputFilter::All));
+
+        assert_eq!(
+            dispatch(&mut contexts, release(KeyCode::W)),
+            vec!["debug_overlay", "gameplay"]
+        );
+        assert_eq!(
+            dispatch(&mut contexts, release(KeyCode::W)),
+            vec!["pause_menu"],
+            "Unmatched releases should be routed normally"
+        );
+    }
+
+    #[test]
+    fn should_route_repeated_presses_to_the_original_contexts() {
+        let mut contexts = game_stack();
+
+        dispatch(&mut contexts, press(KeyCode::W));
+        contexts.push(InputContext::new("pause_menu").with_consumed(InputFilter::All));
+        let repeat = Input::KeyPressed {
+            key: key(KeyCode::W),
+            modifiers: Modifiers::empty(),
+            is_repeat: true,
+        };
+
+        assert_eq!(
+            dispatch(&mut contexts, repeat),
+            vec!["debug_overlay", "gameplay"]
+        );
+    }
+
+    #[test]
+    fn should_never_consume_state_changes() {
+        let mut contexts = game_stack();
+        contexts.push(InputContext::new("pause_menu").with_consumed(InputFilter::All));
+
+        assert_eq!(
+            dispatch(&mut contexts, Input::FocusLost),
+            vec!["pause_menu", "debug_overlay", "gameplay"]
+        );
+    }
+
+    #[test]
+    fn should_forget_held_buttons_on_focus_loss() {
+        let mut contexts = game_stack();
+
+        dispatch(&mut contexts, press(KeyCode::W));
+        dispatch(&mut contexts, Input::FocusLost);
+        contexts.push(InputContext::new("pause_menu").with_consumed(InputFilter::All));
+
+        assert_eq!(
+            dispatch(&mut contexts, press(KeyCode::W)),
+            vec!["pause_menu"]
+        );
+        assert_eq!(
+            dispatch(&mut contexts, release(KeyCode::W)),
+            vec!["pause_menu"]
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use crate::keyboard::{self, Key, KeyCode, LogicalKey, Modifiers};
+use crate::mouse::{MouseButton, ScrollPhase, ScrollUnit};
+use crate::{Input, ToInput};
+
+use crossterm::event::{
+    Event, KeyCode as CrosstermKeyCode, KeyEvent, KeyEventKind, KeyModifiers, MediaKeyCode,
+    ModifierKeyCode, MouseButton as CrosstermMouseButton, MouseEvent, MouseEventKind,
+};
+
+impl ToInput for Event {
+    fn to_input(&self) -> Option<Input> {
+        match self {
+            Event::FocusGained => Some(Input::FocusGained),
+            Event::FocusLost => Some(Input::FocusLost),
+            Event::Key(event) => event.to_input(),
+            Event::Mouse(event) => event.to_input(),
+            _ => None,
+        }
+    }
+}
+
+/// Terminals don't report physical keys, so the [`KeyCode`] is guessed from the character a key
+/// produces, as if it were typed on a US QWERTY keyboard.
+///
+/// Most terminals only report key presses.  Key releases, and repeats are only reported once
+/// keyboard enhancement has been enabled through crossterm's `PushKeyboardEnhancementFlags`.
+/// Without releases, pressed keys stay in an [`InputState`](crate::InputState)'s held keys until
+/// it receives an [`Input::FocusLost`].
+impl ToInput for KeyEvent {
+    fn to_input(&self) -> Option<Input> {
+        let (keycode, logical_key) = match self.code {
+            CrosstermKeyCode::Char(character) => (
+                keyboard::us_keycode(character).map(|(keycode, _)| keycode),
+                LogicalKey::Character(character),
+            ),
+            code => {
+                let keycode = named_keycode(code)?;
+                (Some(keycode), LogicalKey::Named(keycode))
+            }
+        };
+        let key = Key {
+            scancode: 0,
+            keycode,
+            logical_key: Some(logical_key),
+        };
+        let modifiers = self.modifiers.into();
+        match self.kind {
+            KeyEventKind::Press | KeyEventKind::Repeat => Some(Input::KeyPressed {
+                key,
+                modifiers,
+                is_repeat: self.kind == KeyEventKind::Repeat,
+            }),
+            KeyEventKind::Release => Some(Input::KeyReleased { key, modifiers }),
+        }
+    }
+}
+
+/// Mouse positions are measured in terminal cells, rather than pixels.
+///
+/// Terminals report the mouse position with every mouse event, but only moves, and drags are
+/// converted to [`Input::MouseMovedTo`].  Terminals don't always report a move before a click, so
+/// a button press, or release may happen at a different position than the last reported move.
+/// Code which needs the position of a click, such as a
+/// [`GestureRecognizer`](crate::gesture::GestureRecognizer), should send its own
+/// [`Input::MouseMovedTo`], using the event's `column`, and `row`, before the converted input.
+impl ToInput for MouseEvent {
+    fn to_input(&self) -> Option<Input> {
+        let scroll = |delta_x, delta_y| Input::MouseScrolled {
+            delta_x,
+            delta_y,
+            unit: ScrollUnit::Lines,
+            phase: ScrollPhase::Moved,
+        };
+        let input = match self.kind {
+            MouseEventKind::Down(button) => Input::MouseButtonPressed {
+                button: button.into(),
+            },
+            MouseEventKind::Up(button) => Input::MouseButtonReleased {
+                button: button.into(),
+            },
+            MouseEventKind::Drag(_) | MouseEventKind::Moved => Input::MouseMovedTo {
+                x: self.column as f32,
+                y: self.row as f32,
+            },
+            MouseEventKind::ScrollUp => scroll(0.0, 1.0),
+            MouseEventKind::ScrollDown => scroll(0.0, -1.0),
+            MouseEventKind::ScrollLeft => scroll(1.0, 0.0),
+            MouseEventKind::ScrollRight => scroll(-1.0, 0.0),
+        };
+        Some(input)
+    }
+}
+
+fn named_keycode(code: CrosstermKeyCode) -> Option<KeyCode> {
+    let keycode = match code {
+        CrosstermKeyCode::Backspace => KeyCode::Backspace,
+        CrosstermKeyCode::Enter => KeyCode::Enter,
+        CrosstermKeyCode::Left => KeyCode::LeftArrow,
+        CrosstermKeyCode::Right => KeyCode::RightArrow,
+        CrosstermKeyCode::Up => KeyCode::UpArrow,
+        CrosstermKeyCode::Down => KeyCode::DownArrow,
+        CrosstermKeyCode::Home => KeyCode::Home,
+        CrosstermKeyCode::End => KeyCode::End,
+        CrosstermKeyCode::PageUp => KeyCode::PageUp,
+        CrosstermKeyCode::PageDown => KeyCode::PageDown,
+        CrosstermKeyCode::Tab | CrosstermKeyCode::BackTab => KeyCode::Tab,
+        CrosstermKeyCode::Delete => KeyCode::Delete,
+        CrosstermKeyCode::Insert => KeyCode::Insert,
+        CrosstermKeyCode::F(number) => format!("F{number}").parse().ok()?,
+        CrosstermKeyCode::Esc => KeyCode::Escape,
+        CrosstermKeyCode::CapsLock => KeyCode::CapsLock,
+        CrosstermKeyCode::ScrollLock => KeyCode::ScrollLock,
+        CrosstermKeyCode::NumLock => KeyCode::NumLock,
+        CrosstermKeyCode::PrintScreen => KeyCode::PrintScreen,
+        CrosstermKeyCode::Pause => KeyCode::Pause,
+        CrosstermKeyCode::Menu => KeyCode::ContextMenu,
+        CrosstermKeyCode::KeypadBegin => KeyCode::Numpad5,
+        CrosstermKeyCode::Media(media) => media_keycode(media)?,
+        CrosstermKeyCode::Modifier(modifier) => modifier_keycode(modifier)?,
+        CrosstermKeyCode::Char(_) | CrosstermKeyCode::Null => return None,
+    };
+    Some(keycode)
+}
+
+fn media_keycode(media: MediaKeyCode) -> Option<KeyCode> {
+    let keycode = match media {
+        MediaKeyCode::PlayPause => KeyCode::MediaPlayPause,
+        MediaKeyCode::Stop => KeyCode::MediaStop,
+        MediaKeyCode::TrackNext => KeyCode::MediaTrackNext,
+        MediaKeyCode::TrackPrevious => KeyCode::MediaTrackPrevious,
+        MediaKeyCode::LowerVolume => KeyCode::VolumeDown,
+        MediaKeyCode::RaiseVolume => KeyCode::VolumeUp,
+        MediaKeyCode::MuteVolume => KeyCode::VolumeMute,
+        _ => return None,
+    };
+    Some(keycode)
+}
+
+fn modifier_keycode(modifier: ModifierKeyCode) -> Option<KeyCode> {
+    let keycode = match modifier {
+        ModifierKeyCode::LeftShift => KeyCode::LeftShift,
+        ModifierKeyCode::LeftControl => KeyCode::LeftControl,
+        ModifierKeyCode::LeftAlt => KeyCode::LeftAlt,
+        ModifierKeyCode::LeftSuper => KeyCode::LeftSuper,
+        ModifierKeyCode::RightShift => KeyCode::RightShift,
+        ModifierKeyCode::RightControl => KeyCode::RightControl,
+        ModifierKeyCode::RightAlt => KeyCode::RightAlt,
+        ModifierKeyCode::RightSuper => KeyCode::RightSuper,
+        ModifierKeyCode::LeftHyper | ModifierKeyCode::RightHyper => KeyCode::Hyper,
+        ModifierKeyCode::LeftMeta | ModifierKeyCode::RightMeta => KeyCode::Meta,
+        ModifierKeyCode::IsoLevel3Shift | ModifierKeyCode::IsoLevel5Shift => return None,
+    };
+    Some(keycode)
+}
+
+impl From<KeyModifiers> for Modifiers {
+    fn from(key_modifiers: KeyModifiers) -> Self {
+        let mut modifiers = Modifiers::empty();
+        modifiers.set(
+            Modifiers::SHIFT,
+            key_modifiers.contains(KeyModifiers::SHIFT),
+        );
+        modifiers.set(
+            Modifiers::CONTROL,
+            key_modifiers.contains(KeyModifiers::CONTROL),
+        );
+        modifiers.set(Modifiers::ALT, key_modifiers.contains(KeyModifiers::ALT));
+        modifiers.set(
+            Modifiers::SUPER,
+            key_modifiers.contains(KeyModifiers::SUPER),
+        );
+        modifiers
+    }
+}
+
+impl From<CrosstermMouseButton> for MouseButton {
+    fn from(button: CrosstermMouseButton) -> Self {
+        match button {
+            CrosstermMouseButton::Left => MouseButton::Left,
+            CrosstermMouseButton::Right => MouseButton::Right,
+            CrosstermMouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+#[cfg(test)]
+mod crossterm_tests {
+    use crossterm::event::KeyEventState;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn key_event(code: CrosstermKeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> Event {
+        Event::Key(KeyEvent::new_with_kind(code, modifiers, kind))
+    }
+
+    fn mouse_event(kind: MouseEventKind) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: 12,
+            row: 3,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test_case(
+        CrosstermKeyCode::Char('a'),
+        Some(KeyCode::A),
+        LogicalKey::Character('a')
+    )]
+    #[test_case(
+        CrosstermKeyCode::Char('?'),
+        Some(KeyCode::ForwardSlash),
+        LogicalKey::Character('?')
+    )]
+    #[test_case(CrosstermKeyCode::Char('é'), None, LogicalKey::Character('é'))]
+    #[test_case(
+        CrosstermKeyCode::Esc,
+        Some(KeyCode::Escape),
+        LogicalKey::Named(KeyCode::Escape)
+    )]
+    #[test_case(
+        CrosstermKeyCode::F(13),
+        Some(KeyCode::F13),
+        LogicalKey::Named(KeyCode::F13)
+    )]
+    #[test_case(
+        CrosstermKeyCode::Left,
+        Some(KeyCode::LeftArrow),
+        LogicalKey::Named(KeyCode::LeftArrow)
+    )]
+    #[test_case(
+        CrosstermKeyCode::Modifier(ModifierKeyCode::RightShift),
+        Some(KeyCode::RightShift),
+        LogicalKey::Named(KeyCode::RightShift)
+    )]
+    fn should_convert_key_presses(
+        code: CrosstermKeyCode,
+        keycode: Option<KeyCode>,
+        logical_key: LogicalKey,
+    ) {
+        let event = key_event(code, KeyModifiers::NONE, KeyEventKind::Press);
+
+        assert_eq!(
+            event.to_input(),
+            Some(Input::KeyPressed {
+                key: Key {
+                    scancode: 0,
+                    keycode,
+                    logical_key: Some(logical_key),
+                },
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            })
+        );
+    }
+
+    #[test]
+    fn should_convert_key_modifiers_repeats_and_releases() {
+        let key = Key {
+            scancode: 0,
+            keycode: Some(KeyCode::S),
+            logical_key: Some(LogicalKey::Character('S')),
+        };
+        let modifiers = KeyModifiers::CONTROL | KeyModifiers::SHIFT | KeyModifiers::HYPER;
+
+        assert_eq!(
+            key_event(CrosstermKeyCode::Char('S'), modifiers, KeyEventKind::Repeat).to_input(),
+            Some(Input::KeyPressed {
+                key,
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                is_repeat: true,
+            })
+        );
+        assert_eq!(
+            Event::Key(KeyEvent::new_with_kind_and_state(
+                CrosstermKeyCode::Char('S'),
+                KeyModifiers::NONE,
+                KeyEventKind::Release,
+                KeyEventState::NONE,
+            ))
+            .to_input(),
+            Some(Input::KeyReleased {
+                key,
+                modifiers: Modifiers::empty(),
+            })
+        );
+        assert_eq!(
+            key_event(
+                CrosstermKeyCode::Null,
+                KeyModifiers::NONE,
+                KeyEventKind::Press
+            )
+            .to_input(),
+            None
+        );
+    }
+
+    #[test_case(MouseEventKind::Down(CrosstermMouseButton::Left), Input::MouseButtonPressed { button: MouseButton::Left })]
+    #[test_case(MouseEventKind::Up(CrosstermMouseButton::Right), Input::MouseButtonReleased { button: MouseButton::Right })]
+    #[test_case(MouseEventKind::Moved, Input::MouseMovedTo { x: 12.0, y: 3.0 })]
+    #[test_case(MouseEventKind::Drag(CrosstermMouseButton::Middle), Input::MouseMovedTo { x: 12.0, y: 3.0 })]
+    #[test_case(
+        MouseEventKind::ScrollDown,
+        Input::MouseScrolled { delta_x: 0.0, delta_y: -1.0, unit: ScrollUnit::Lines, phase: ScrollPhase::Moved }
+    )]
+    #[test_case(
+        MouseEventKind::ScrollLeft,
+        Input::MouseScrolled { delta_x: 1.0, delta_y: 0.0, unit: ScrollUnit::Lines, phase: ScrollPhase::Moved }
+    )]
+    fn should_convert_mouse_events(kind: MouseEventKind, expected: Input) {
+        assert_eq!(mouse_event(kind).to_input(), Some(expected));
+    }
+
+    #[test]
+    fn should_convert_focus_events() {
+        assert_eq!(Event::FocusGained.to_input(), Some(Input::FocusGained));
+        assert_eq!(Event::FocusLost.to_input(), Some(Input::FocusLost));
+        assert_eq!(Event::Resize(80, 24).to_input(), None);
+    }
+}
@@ -0,0 +1,111 @@
+//! Provides types for identifying input devices, and assigning them to players.
+
+use std::collections::HashMap;
+
+/// Identifies an input device, such as a keyboard, or mouse.
+///
+/// Ids are assigned by the backend, and stay the same for as long as it is running, even if the
+/// device is disconnected, and reconnected.  Some platforms don't tell devices apart, and report
+/// all input as coming from a single device, or from no device at all.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceId(pub u64);
+
+/// Assigns input devices to players, for local multiplayer games.
+///
+/// Each device is assigned to at most one player, but a player may have several devices, such as
+/// a keyboard, and a mouse.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_input::device::{DeviceAssignments, DeviceId};
+/// #
+/// let mut assignments = DeviceAssignments::default();
+/// let keyboard = DeviceId(1);
+/// let mouse = DeviceId(2);
+/// let second_keyboard = DeviceId(3);
+///
+/// assignments.assign(keyboard, 0);
+/// assignments.assign(mouse, 0);
+/// assignments.assign(second_keyboard, 1);
+///
+/// assert_eq!(assignments.player(second_keyboard), Some(1));
+/// assert_eq!(assignments.devices(0), vec![keyboard, mouse]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DeviceAssignments {
+    players: HashMap<DeviceId, usize>,
+}
+
+impl DeviceAssignments {
+    /// Assign a device to a player, replacing any previous assignment.
+    pub fn assign(&mut self, device_id: DeviceId, player: usize) {
+        self.players.insert(device_id, player);
+    }
+
+    /// Remove a device's assignment, returning the player it was assigned to.
+    pub fn unassign(&mut self, device_id: DeviceId) -> Option<usize> {
+        self.players.remove(&device_id)
+    }
+
+    /// Remove all of a player's devices.
+    pub fn unassign_player(&mut self, player: usize) {
+        self.players
+            .retain(|_, assigned_player| *assigned_player != player);
+    }
+
+    /// Get the player a device is assigned to, if any.
+    pub fn player(&self, device_id: DeviceId) -> Option<usize> {
+        self.players.get(&device_id).copied()
+    }
+
+    /// Get the devices assigned to a player, sorted by id.
+    pub fn devices(&self, player: usize) -> Vec<DeviceId> {
+        let mut devices = self
+            .players
+            .iter()
+            .filter(|(_, assigned_player)| **assigned_player == player)
+            .map(|(device_id, _)| *device_id)
+            .collect::<Vec<_>>();
+        devices.sort();
+        devices
+    }
+
+    /// Returns true if the device is assigned to a player.
+    pub fn is_assigned(&self, device_id: DeviceId) -> bool {
+        self.players.contains_key(&device_id)
+    }
+}
+
+#[cfg(test)]
+mod device_assignments_tests {
+    use super::*;
+
+    #[test]
+    fn should_reassign_devices() {
+        let mut assignments = DeviceAssignments::default();
+
+        assignments.assign(DeviceId(1), 0);
+        assignments.assign(DeviceId(1), 1);
+
+        assert_eq!(assignments.player(DeviceId(1)), Some(1));
+        assert!(assignments.devices(0).is_empty());
+    }
+
+    #[test]
+    fn should_unassign_devices() {
+        let mut assignments = DeviceAssignments::default();
+        assignments.assign(DeviceId(1), 0);
+        assignments.assign(DeviceId(2), 0);
+        assignments.assign(DeviceId(3), 1);
+
+        assert_eq!(assignments.unassign(DeviceId(3)), Some(1));
+        assert_eq!(assignments.unassign(DeviceId(3)), None);
+        assert!(!assignments.is_assigned(DeviceId(3)));
+
+        assignments.unassign_player(0);
+        assert!(assignments.devices(0).is_empty());
+        assert!(!assignments.is_assigned(DeviceId(1)));
+    }
+}
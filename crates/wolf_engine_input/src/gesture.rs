@@ -0,0 +1,590 @@
+//! Provides recognition of higher-level mouse, and touch gestures.
+//!
+//! A [`GestureRecognizer`] watches the raw button edges, and motion of a stream of [`Input`]s, and
+//! turns them into [`Gesture`]s, such as double-clicks, long-presses, and drags.
+//!
+//! # Touch Input
+//!
+//! The primary touch (the first finger to touch the screen, while no other press is being
+//! tracked) is recognized as if it were the left mouse button.  Only one press is tracked at a
+//! time, so touches which are also emulated as mouse input by
+//! [`InputState::with_mouse_emulation()`](crate::InputState::with_mouse_emulation) are not
+//! recognized twice.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use wolf_engine_input::{gesture::{Gesture, GestureRecognizer}, mouse::MouseButton, Input};
+//! #
+//! let mut gesture_recognizer = GestureRecognizer::default();
+//! let mut gestures = Vec::new();
+//!
+//! // Inputs are fed in along with the time they happened.
+//! for (input, time) in [
+//!     (Input::MouseMovedTo { x: 10.0, y: 20.0 }, 0),
+//!     (Input::MouseButtonPressed { button: MouseButton::Left }, 0),
+//!     (Input::MouseButtonReleased { button: MouseButton::Left }, 80),
+//!     (Input::MouseButtonPressed { button: MouseButton::Left }, 160),
+//!     (Input::MouseButtonReleased { button: MouseButton::Left }, 240),
+//! ] {
+//!     gesture_recognizer.process(&input, Duration::from_millis(time), |gesture| {
+//!         gestures.push(gesture)
+//!     });
+//! }
+//!
+//! assert_eq!(
+//!     gestures,
+//!     vec![Gesture::DoubleClicked { button: MouseButton::Left, x: 10.0, y: 20.0 }],
+//! );
+//! ```
+
+use std::time::Duration;
+
+use crate::{mouse::MouseButton, touch::Touch, Input};
+
+/// Provides a set of higher-level gestures, recognized by a [`GestureRecognizer`].
+///
+/// Positions are in the same coordinates as [`Input::MouseMovedTo`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Gesture {
+    /// A button was clicked twice in quick succession, without moving far.
+    DoubleClicked { button: MouseButton, x: f32, y: f32 },
+
+    /// A button was held without moving far, for longer than the long-press time.
+    LongPressed { button: MouseButton, x: f32, y: f32 },
+
+    /// A button was held, and moved further than the drag distance.
+    ///
+    /// The position is where the button was first pressed.
+    DragStarted { button: MouseButton, x: f32, y: f32 },
+
+    /// The position of an ongoing drag has changed.
+    Dragged {
+        button: MouseButton,
+        x: f32,
+        y: f32,
+        delta_x: f32,
+        delta_y: f32,
+    },
+
+    /// A drag has ended, because the button was released, or the press was cancelled.
+    DragEnded { button: MouseButton, x: f32, y: f32 },
+}
+
+/// The source of the press being tracked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pointer {
+    Mouse(MouseButton),
+    Touch(u64),
+}
+
+impl Pointer {
+    fn button(&self) -> MouseButton {
+        match self {
+            Pointer::Mouse(button) => *button,
+            Pointer::Touch(_) => MouseButton::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    pointer: Pointer,
+    start: (f32, f32),
+    position: (f32, f32),
+    time: Duration,
+    is_long_press: bool,
+    is_dragging: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Click {
+    pointer: Pointer,
+    position: (f32, f32),
+    time: Duration,
+}
+
+/// Recognizes [`Gesture`]s from a stream of [`Input`]s.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Debug, Clone)]
+pub struct GestureRecognizer {
+    double_click_time: Duration,
+    double_click_distance: f32,
+    long_press_time: Duration,
+    drag_distance: f32,
+    mouse_position: (f32, f32),
+    press: Option<Press>,
+    last_click: Option<Click>,
+}
+
+impl GestureRecognizer {
+    /// Set the longest time between two clicks which counts as a double-click.
+    pub fn with_double_click_time(mut self, double_click_time: Duration) -> Self {
+        self.double_click_time = double_click_time;
+        self
+    }
+
+    /// Set the furthest distance between two clicks which counts as a double-click.
+    pub fn with_double_click_distance(mut self, double_click_distance: f32) -> Self {
+        self.double_click_distance = double_click_distance;
+        self
+    }
+
+    /// Set how long a button must be held to count as a long-press.
+    pub fn with_long_press_time(mut self, long_press_time: Duration) -> Self {
+        self.long_press_time = long_press_time;
+        self
+    }
+
+    /// Set how far a held button must move before it counts as a drag.
+    pub fn with_drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance;
+        self
+    }
+
+    /// Forget the current press, and the last click, ending any ongoing drag.
+    pub fn reset<F: FnMut(Gesture)>(&mut self, handler: F) {
+        self.cancel(handler);
+        self.last_click = None;
+    }
+
+    /// Process an input which happened at `time`, and pass the gestures it completes on to the
+    /// handler.
+    ///
+    /// The time can be measured from any starting point, but it must never go backwards.
+    pub fn process<F: FnMut(Gesture)>(&mut self, input: &Input, time: Duration, mut handler: F) {
+        self.update(time, &mut handler);
+        match input {
+            Input::MouseMovedTo { x, y } => {
+                self.mouse_position = (*x, *y);
+                if let Some(Pointer::Mouse(_)) = self.press.map(|press| press.pointer) {
+                    self.move_to((*x, *y), handler);
+                }
+            }
+            Input::MouseButtonPressed { button } => {
+                self.press(Pointer::Mouse(*button), self.mouse_position, time)
+            }
+            Input::MouseButtonReleased { button } => {
+                self.release(Pointer::Mouse(*button), time, handler)
+            }
+            Input::TouchStarted { touch } => {
+                self.press(Pointer::Touch(touch.finger_id), position(touch), time)
+            }
+            Input::TouchMoved { touch } if self.is_pressed(Pointer::Touch(touch.finger_id)) => {
+                self.move_to(position(touch), handler)
+            }
+            Input::TouchEnded { touch } => {
+                if self.is_pressed(Pointer::Touch(touch.finger_id)) {
+                    self.move_to(position(touch), &mut handler);
+                }
+                self.release(Pointer::Touch(touch.finger_id), time, handler)
+            }
+            Input::TouchCancelled { touch } if self.is_pressed(Pointer::Touch(touch.finger_id)) => {
+                self.cancel(handler)
+            }
+            Input::FocusLost => self.reset(handler),
+            _ => (),
+        }
+    }
+
+    /// Recognize gestures which complete with the passage of time, such as long-presses.
+    ///
+    /// [`GestureRecognizer::process()`] calls this automatically, but it should also be called
+    /// each frame, so long-presses are recognized while no inputs are arriving.
+    pub fn update<F: FnMut(Gesture)>(&mut self, time: Duration, mut handler: F) {
+        if let Some(press) = &mut self.press {
+            if !press.is_long_press
+                && !press.is_dragging
+                && time.saturating_sub(press.time) >= self.long_press_time
+            {
+                press.is_long_press = true;
+                handler(Gesture::LongPressed {
+                    button: press.pointer.button(),
+                    x: press.position.0,
+                    y: press.position.1,
+                });
+            }
+        }
+    }
+
+    fn is_pressed(&self, pointer: Pointer) -> bool {
+        self.press.is_some_and(|press| press.pointer == pointer)
+    }
+
+    fn press(&mut self, pointer: Pointer, position: (f32, f32), time: Duration) {
+        if self.press.is_none() {
+            self.press = Some(Press {
+                pointer,
+                start: position,
+                position,
+                time,
+                is_long_press: false,
+                is_dragging: false,
+            });
+        }
+    }
+
+    fn move_to<F: FnMut(Gesture)>(&mut self, position: (f32, f32), mut handler: F) {
+        let Some(press) = &mut self.press else {
+            return;
+        };
+        let button = press.pointer.button();
+        if !press.is_dragging && distance(press.start, position) >= self.drag_distance {
+            press.is_dragging = true;
+            handler(Gesture::DragStarted {
+                button,
+                x: press.start.0,
+                y: press.start.1,
+            });
+        }
+        if press.is_dragging && position != press.position {
+            handler(Gesture::Dragged {
+                button,
+                x: position.0,
+                y: position.1,
+                delta_x: position.0 - press.position.0,
+                delta_y: position.1 - press.position.1,
+            });
+        }
+        press.position = position;
+    }
+
+    fn release<F: FnMut(Gesture)>(&mut self, pointer: Pointer, time: Duration, handler: F) {
+        match self.press {
+            Some(press) if press.pointer == pointer => {
+                if press.is_dragging || press.is_long_press {
+                    self.cancel(handler);
+                    return;
+                }
+                self.press = None;
+                self.click(pointer, press.position, time, handler);
+            }
+            _ => (),
+        }
+    }
+
+    fn click<F: FnMut(Gesture)>(
+        &mut self,
+        pointer: Pointer,
+        position: (f32, f32),
+        time: Duration,
+        mut handler: F,
+    ) {
+        match self.last_click.take() {
+            Some(click)
+                if click.pointer.button() == pointer.button()
+                    && time.saturating_sub(click.time) <= self.double_click_time
+                    && distance(click.position, position) <= self.double_click_distance =>
+            {
+                handler(Gesture::DoubleClicked {
+                    button: pointer.button(),
+                    x: position.0,
+                    y: position.1,
+                });
+            }
+            _ => {
+                self.last_click = Some(Click {
+                    pointer,
+                    position,
+                    time,
+                })
+            }
+        }
+    }
+
+    fn cancel<F: FnMut(Gesture)>(&mut self, mut handler: F) {
+        if let Some(press) = self.press.take() {
+            if press.is_dragging {
+                handler(Gesture::DragEnded {
+                    button: press.pointer.button(),
+                    x: press.position.0,
+                    y: press.position.1,
+                });
+            }
+        }
+    }
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        Self {
+            double_click_time: Duration::from_millis(500),
+            double_click_distance: 4.0,
+            long_press_time: Duration::from_millis(500),
+            drag_distance: 4.0,
+            mouse_position: (0.0, 0.0),
+            press: None,
+            last_click: None,
+        }
+    }
+}
+
+fn position(touch: &Touch) -> (f32, f32) {
+    (touch.x, touch.y)
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+#[cfg(test)]
+mod gesture_recognizer_tests {
+    use super::*;
+
+    fn process(recognizer: &mut GestureRecognizer, inputs: &[(Input, u64)]) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+        for (input, time) in inputs {
+            recognizer.process(input, Duration::from_millis(*time), |gesture| {
+                gestures.push(gesture)
+            });
+        }
+        gestures
+    }
+
+    fn pressed(button: MouseButton) -> Input {
+        Input::MouseButtonPressed { button }
+    }
+
+    fn released(button: MouseButton) -> Input {
+        Input::MouseButtonReleased { button }
+    }
+
+    fn touch(finger_id: u64, x: f32, y: f32) -> Touch {
+        Touch {
+            finger_id,
+            x,
+            y,
+            pressure: None,
+        }
+    }
+
+    #[test]
+    fn should_recognize_double_clicks() {
+        let mut recognizer = GestureRecognizer::default();
+
+        let gestures = process(
+            &mut recognizer,
+            &[
+                (pressed(MouseButton::Right), 0),
+                (released(MouseButton::Right), 50),
+                (pressed(MouseButton::Right), 100),
+                (released(MouseButton::Right), 150),
+                (pressed(MouseButton::Right), 200),
+                (released(MouseButton::Right), 250),
+            ],
+        );
+
+        assert_eq!(
+            gestures,
+            vec![Gesture::DoubleClicked {
+                button: MouseButton::Right,
+                x: 0.0,
+                y: 0.0
+            }],
+            "The third click should start a new double-click"
+        );
+    }
+
+    #[test]
+    fn should_not_recognize_slow_or_distant_double_clicks() {
+        let mut recognizer = GestureRecognizer::default()
+            .with_double_click_time(Duration::from_millis(200))
+            .with_drag_distance(100.0);
+
+        let gestures = process(
+            &mut recognizer,
+            &[
+                (pressed(MouseButton::Left), 0),
+                (released(MouseButton::Left), 50),
+                (pressed(MouseButton::Left), 300),
+                (released(MouseButton::Left), 350),
+                (Input::MouseMovedTo { x: 50.0, y: 0.0 }, 360),
+                (pressed(MouseButton::Left), 370),
+                (released(MouseButton::Left), 380),
+            ],
+        );
+
+        assert!(gestures.is_empty(), "{gestures:?}");
+    }
+
+    #[test]
+    fn should_recognize_long_presses() {
+        let mut recognizer =
+            GestureRecognizer::default().with_long_press_time(Duration::from_millis(300));
+        let mut gestures = process(&mut recognizer, &[(pressed(MouseButton::Left), 0)]);
+
+        recognizer.update(Duration::from_millis(200), |gesture| gestures.push(gesture));
+        assert!(gestures.is_empty());
+        recognizer.update(Duration::from_millis(300), |gesture| gestures.push(gesture));
+        recognizer.update(Duration::from_millis(400), |gesture| gestures.push(gesture));
+        gestures.extend(process(
+            &mut recognizer,
+            &[
+                (released(MouseButton::Left), 500),
+                (pressed(MouseButton::Left), 550),
+                (released(MouseButton::Left), 600),
+            ],
+        ));
+
+        assert_eq!(
+            gestures,
+            vec![Gesture::LongPressed {
+                button: MouseButton::Left,
+                x: 0.0,
+                y: 0.0
+            }],
+            "A long-press should not count as a click"
+        );
+    }
+
+    #[test]
+    fn should_recognize_drags() {
+        let mut recognizer = GestureRecognizer::default().with_drag_distance(10.0);
+
+        let gestures = process(
+            &mut recognizer,
+            &[
+                (Input::MouseMovedTo { x: 5.0, y: 5.0 }, 0),
+                (pressed(MouseButton::Left), 0),
+                (Input::MouseMovedTo { x: 8.0, y: 5.0 }, 10),
+                (Input::MouseMovedTo { x: 20.0, y: 5.0 }, 20),
+                (Input::MouseMovedTo { x: 20.0, y: 10.0 }, 30),
+                (released(MouseButton::Left), 40),
+            ],
+        );
+
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::DragStarted {
+                    button: MouseButton::Left,
+                    x: 5.0,
+                    y: 5.0
+                },
+                Gesture::Dragged {
+                    button: MouseButton::Left,
+                    x: 20.0,
+                    y: 5.0,
+                    delta_x: 12.0,
+                    delta_y: 0.0
+                },
+                Gesture::Dragged {
+                    button: MouseButton::Left,
+                    x: 20.0,
+                    y: 10.0,
+                    delta_x: 0.0,
+                    delta_y: 5.0
+                },
+                Gesture::DragEnded {
+                    button: MouseButton::Left,
+                    x: 20.0,
+                    y: 10.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_recognize_primary_touch_gestures() {
+        let mut recognizer = GestureRecognizer::default();
+
+        let gestures = process(
+            &mut recognizer,
+            &[
+                (
+                    Input::TouchStarted {
+                        touch: touch(1, 0.0, 0.0),
+                    },
+                    0,
+                ),
+                // A second finger should be ignored.
+                (
+                    Input::TouchStarted {
+                        touch: touch(2, 50.0, 50.0),
+                    },
+                    10,
+                ),
+                (
+                    Input::TouchEnded {
+                        touch: touch(2, 90.0, 90.0),
+                    },
+                    20,
+                ),
+                (
+                    Input::TouchMoved {
+                        touch: touch(1, 30.0, 40.0),
+                    },
+                    30,
+                ),
+                (
+                    Input::TouchCancelled {
+                        touch: touch(1, 30.0, 40.0),
+                    },
+                    40,
+                ),
+            ],
+        );
+
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::DragStarted {
+                    button: MouseButton::Left,
+                    x: 0.0,
+                    y: 0.0
+                },
+                Gesture::Dragged {
+                    button: MouseButton::Left,
+                    x: 30.0,
+                    y: 40.0,
+                    delta_x: 30.0,
+                    delta_y: 40.0
+                },
+                Gesture::DragEnded {
+                    button: MouseButton::Left,
+                    x: 30.0,
+                    y: 40.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_recognize_emulated_touches_twice() {
+        let mut recognizer = GestureRecognizer::default();
+        let mut inputs = Vec::new();
+        for time in [0, 100] {
+            inputs.extend([
+                (
+                    Input::TouchStarted {
+                        touch: touch(1, 4.0, 4.0),
+                    },
+                    time,
+                ),
+                (Input::MouseMovedTo { x: 4.0, y: 4.0 }, time),
+                (pressed(MouseButton::Left), time),
+                (
+                    Input::TouchEnded {
+                        touch: touch(1, 4.0, 4.0),
+                    },
+                    time + 50,
+                ),
+                (released(MouseButton::Left), time + 50),
+            ]);
+        }
+
+        let gestures = process(&mut recognizer, &inputs);
+
+        assert_eq!(
+            gestures,
+            vec![Gesture::DoubleClicked {
+                button: MouseButton::Left,
+                x: 4.0,
+                y: 4.0
+            }]
+        );
+    }
+}
@@ -1,16 +1,44 @@
 //! Provides types related to keyboard input.
 
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use bitflags::bitflags;
+
+use crate::ParseInputError;
+
 /// Represents a key on the keyboard.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Key {
     /// The platform-specific scancode of the key.
     pub scancode: u32,
     /// The common name of the key, if it's known, otherwise None.
+    ///
+    /// This identifies the physical key, as if it were on a US QWERTY keyboard, and is not
+    /// affected by the keyboard layout.
     pub keycode: Option<KeyCode>,
+    /// The meaning of the key under the current keyboard layout, if it's known, otherwise None.
+    pub logical_key: Option<LogicalKey>,
 }
 
-/// Provides a set of named keys based on a US 104-key QWERTY keyboard.
+/// Represents the meaning of a key under the current keyboard layout.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicalKey {
+    /// The key produces a character.
+    ///
+    /// The character may be affected by the held modifier keys.  For example: Shift + 1 produces
+    /// `'!'` on a US QWERTY keyboard.
+    Character(char),
+    /// The key doesn't produce a character, but has a name, such as Enter, Escape, or the arrow
+    /// keys.
+    Named(KeyCode),
+}
+
+/// Provides a set of named keys based on a US 104-key QWERTY keyboard.
+///
+/// Keys found on extended, international, and multimedia keyboards are also included.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[non_exhaustive]
 pub enum KeyCode {
     Escape,
@@ -122,5 +150,776 @@ pub enum KeyCode {
     Numpad0,
     NumpadDecimal,
 
+    // Function keys found on extended keyboards.
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    F25,
+    F26,
+    F27,
+    F28,
+    F29,
+    F30,
+    F31,
+    F32,
+    F33,
+    F34,
+    F35,
+
+    // Numpad keys found on extended keyboards.
+    NumpadBackspace,
+    NumpadClear,
+    NumpadClearEntry,
+    NumpadComma,
+    NumpadEquals,
+    NumpadHash,
+    NumpadStar,
+    NumpadLeftParen,
+    NumpadRightParen,
+    NumpadMemoryAdd,
+    NumpadMemoryClear,
+    NumpadMemoryRecall,
+    NumpadMemoryStore,
+    NumpadMemorySubtract,
+
+    // Keys found on international keyboards.
+    IntlBackslash,
+    IntlRo,
+    IntlYen,
+    Convert,
+    NonConvert,
+    KanaMode,
+    Hiragana,
+    Katakana,
+    Lang1,
+    Lang2,
+    Lang3,
+    Lang4,
+    Lang5,
+
+    // Function, and editing keys found on some keyboards.
+    ContextMenu,
+    Fn,
+    FnLock,
+    Help,
+    Meta,
+    Hyper,
+    Turbo,
+    Abort,
+    Resume,
+    Suspend,
+    Again,
+    Copy,
+    Cut,
+    Paste,
+    Find,
+    Open,
+    Props,
+    Select,
+    Undo,
+
+    // Media, browser, and system keys.
+    MediaPlayPause,
+    MediaStop,
+    MediaTrackNext,
+    MediaTrackPrevious,
+    MediaSelect,
+    VolumeDown,
+    VolumeMute,
+    VolumeUp,
+    BrowserBack,
+    BrowserForward,
+    BrowserRefresh,
+    BrowserStop,
+    BrowserSearch,
+    BrowserFavorites,
+    BrowserHome,
+    LaunchMail,
+    LaunchApp1,
+    LaunchApp2,
+    Eject,
+    Power,
+    Sleep,
+    WakeUp,
+
     Unknown,
 }
+
+impl KeyCode {
+    /// All known key codes, in keyboard order.
+    ///
+    /// [`KeyCode::Unknown`] is not included.
+    pub const ALL: &'static [KeyCode] = &[
+        KeyCode::Escape,
+        KeyCode::F1,
+        KeyCode::F2,
+        KeyCode::F3,
+        KeyCode::F4,
+        KeyCode::F5,
+        KeyCode::F6,
+        KeyCode::F7,
+        KeyCode::F8,
+        KeyCode::F9,
+        KeyCode::F10,
+        KeyCode::F11,
+        KeyCode::F12,
+        KeyCode::PrintScreen,
+        KeyCode::ScrollLock,
+        KeyCode::Pause,
+        KeyCode::Grave,
+        KeyCode::Num1,
+        KeyCode::Num2,
+        KeyCode::Num3,
+        KeyCode::Num4,
+        KeyCode::Num5,
+        KeyCode::Num6,
+        KeyCode::Num7,
+        KeyCode::Num8,
+        KeyCode::Num9,
+        KeyCode::Num0,
+        KeyCode::Minus,
+        KeyCode::Equals,
+        KeyCode::BackSlash,
+        KeyCode::Backspace,
+        KeyCode::Insert,
+        KeyCode::Home,
+        KeyCode::PageUp,
+        KeyCode::NumLock,
+        KeyCode::NumpadDivide,
+        KeyCode::NumpadMultiply,
+        KeyCode::NumpadSubtract,
+        KeyCode::Tab,
+        KeyCode::Q,
+        KeyCode::W,
+        KeyCode::E,
+        KeyCode::R,
+        KeyCode::T,
+        KeyCode::Y,
+        KeyCode::U,
+        KeyCode::I,
+        KeyCode::O,
+        KeyCode::P,
+        KeyCode::LeftBracket,
+        KeyCode::RightBracket,
+        KeyCode::Delete,
+        KeyCode::End,
+        KeyCode::PageDown,
+        KeyCode::Numpad7,
+        KeyCode::Numpad8,
+        KeyCode::Numpad9,
+        KeyCode::NumpadAdd,
+        KeyCode::CapsLock,
+        KeyCode::A,
+        KeyCode::S,
+        KeyCode::D,
+        KeyCode::F,
+        KeyCode::G,
+        KeyCode::H,
+        KeyCode::J,
+        KeyCode::K,
+        KeyCode::L,
+        KeyCode::Semicolon,
+        KeyCode::Quote,
+        KeyCode::Enter,
+        KeyCode::Numpad4,
+        KeyCode::Numpad5,
+        KeyCode::Numpad6,
+        KeyCode::LeftShift,
+        KeyCode::Z,
+        KeyCode::X,
+        KeyCode::C,
+        KeyCode::V,
+        KeyCode::B,
+        KeyCode::N,
+        KeyCode::M,
+        KeyCode::Comma,
+        KeyCode::Period,
+        KeyCode::ForwardSlash,
+        KeyCode::RightShift,
+        KeyCode::UpArrow,
+        KeyCode::Numpad1,
+        KeyCode::Numpad2,
+        KeyCode::Numpad3,
+        KeyCode::NumpadEnter,
+        KeyCode::LeftControl,
+        KeyCode::LeftSuper,
+        KeyCode::LeftAlt,
+        KeyCode::Space,
+        KeyCode::RightAlt,
+        KeyCode::RightSuper,
+        KeyCode::RightControl,
+        KeyCode::LeftArrow,
+        KeyCode::DownArrow,
+        KeyCode::RightArrow,
+        KeyCode::Numpad0,
+        KeyCode::NumpadDecimal,
+        KeyCode::F13,
+        KeyCode::F14,
+        KeyCode::F15,
+        KeyCode::F16,
+        KeyCode::F17,
+        KeyCode::F18,
+        KeyCode::F19,
+        KeyCode::F20,
+        KeyCode::F21,
+        KeyCode::F22,
+        KeyCode::F23,
+        KeyCode::F24,
+        KeyCode::F25,
+        KeyCode::F26,
+        KeyCode::F27,
+        KeyCode::F28,
+        KeyCode::F29,
+        KeyCode::F30,
+        KeyCode::F31,
+        KeyCode::F32,
+        KeyCode::F33,
+        KeyCode::F34,
+        KeyCode::F35,
+        KeyCode::NumpadBackspace,
+        KeyCode::NumpadClear,
+        KeyCode::NumpadClearEntry,
+        KeyCode::NumpadComma,
+        KeyCode::NumpadEquals,
+        KeyCode::NumpadHash,
+        KeyCode::NumpadStar,
+        KeyCode::NumpadLeftParen,
+        KeyCode::NumpadRightParen,
+        KeyCode::NumpadMemoryAdd,
+        KeyCode::NumpadMemoryClear,
+        KeyCode::NumpadMemoryRecall,
+        KeyCode::NumpadMemoryStore,
+        KeyCode::NumpadMemorySubtract,
+        KeyCode::IntlBackslash,
+        KeyCode::IntlRo,
+        KeyCode::IntlYen,
+        KeyCode::Convert,
+        KeyCode::NonConvert,
+        KeyCode::KanaMode,
+        KeyCode::Hiragana,
+        KeyCode::Katakana,
+        KeyCode::Lang1,
+        KeyCode::Lang2,
+        KeyCode::Lang3,
+        KeyCode::Lang4,
+        KeyCode::Lang5,
+        KeyCode::ContextMenu,
+        KeyCode::Fn,
+        KeyCode::FnLock,
+        KeyCode::Help,
+        KeyCode::Meta,
+        KeyCode::Hyper,
+        KeyCode::Turbo,
+        KeyCode::Abort,
+        KeyCode::Resume,
+        KeyCode::Suspend,
+        KeyCode::Again,
+        KeyCode::Copy,
+        KeyCode::Cut,
+        KeyCode::Paste,
+        KeyCode::Find,
+        KeyCode::Open,
+        KeyCode::Props,
+        KeyCode::Select,
+        KeyCode::Undo,
+        KeyCode::MediaPlayPause,
+        KeyCode::MediaStop,
+        KeyCode::MediaTrackNext,
+        KeyCode::MediaTrackPrevious,
+        KeyCode::MediaSelect,
+        KeyCode::VolumeDown,
+        KeyCode::VolumeMute,
+        KeyCode::VolumeUp,
+        KeyCode::BrowserBack,
+        KeyCode::BrowserForward,
+        KeyCode::BrowserRefresh,
+        KeyCode::BrowserStop,
+        KeyCode::BrowserSearch,
+        KeyCode::BrowserFavorites,
+        KeyCode::BrowserHome,
+        KeyCode::LaunchMail,
+        KeyCode::LaunchApp1,
+        KeyCode::LaunchApp2,
+        KeyCode::Eject,
+        KeyCode::Power,
+        KeyCode::Sleep,
+        KeyCode::WakeUp,
+    ];
+
+    /// Get a human-readable label for the key, as printed on a US QWERTY keyboard.
+    ///
+    /// Use [`KeyboardLayout::label()`] to get a label for the current keyboard layout.
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyCode::Escape => "Esc",
+            KeyCode::F1 => "F1",
+            KeyCode::F2 => "F2",
+            KeyCode::F3 => "F3",
+            KeyCode::F4 => "F4",
+            KeyCode::F5 => "F5",
+            KeyCode::F6 => "F6",
+            KeyCode::F7 => "F7",
+            KeyCode::F8 => "F8",
+            KeyCode::F9 => "F9",
+            KeyCode::F10 => "F10",
+            KeyCode::F11 => "F11",
+            KeyCode::F12 => "F12",
+            KeyCode::PrintScreen => "Print Screen",
+            KeyCode::ScrollLock => "Scroll Lock",
+            KeyCode::Pause => "Pause",
+            KeyCode::Grave => "`",
+            KeyCode::Num1 => "1",
+            KeyCode::Num2 => "2",
+            KeyCode::Num3 => "3",
+            KeyCode::Num4 => "4",
+            KeyCode::Num5 => "5",
+            KeyCode::Num6 => "6",
+            KeyCode::Num7 => "7",
+            KeyCode::Num8 => "8",
+            KeyCode::Num9 => "9",
+            KeyCode::Num0 => "0",
+            KeyCode::Minus => "-",
+            KeyCode::Equals => "=",
+            KeyCode::BackSlash => "\\",
+            KeyCode::Backspace => "Backspace",
+            KeyCode::Insert => "Insert",
+            KeyCode::Home => "Home",
+            KeyCode::PageUp => "Page Up",
+            KeyCode::NumLock => "Num Lock",
+            KeyCode::NumpadDivide => "Numpad /",
+            KeyCode::NumpadMultiply => "Numpad *",
+            KeyCode::NumpadSubtract => "Numpad -",
+            KeyCode::Tab => "Tab",
+            KeyCode::Q => "Q",
+            KeyCode::W => "W",
+            KeyCode::E => "E",
+            KeyCode::R => "R",
+            KeyCode::T => "T",
+            KeyCode::Y => "Y",
+            KeyCode::U => "U",
+            KeyCode::I => "I",
+            KeyCode::O => "O",
+            KeyCode::P => "P",
+            KeyCode::LeftBracket => "[",
+            KeyCode::RightBracket => "]",
+            KeyCode::Delete => "Delete",
+            KeyCode::End => "End",
+            KeyCode::PageDown => "Page Down",
+            KeyCode::Numpad7 => "Numpad 7",
+            KeyCode::Numpad8 => "Numpad 8",
+            KeyCode::Numpad9 => "Numpad 9",
+            KeyCode::NumpadAdd => "Numpad +",
+            KeyCode::CapsLock => "Caps Lock",
+            KeyCode::A => "A",
+            KeyCode::S => "S",
+            KeyCode::D => "D",
+            KeyCode::F => "F",
+            KeyCode::G => "G",
+            KeyCode::H => "H",
+            KeyCode::J => "J",
+            KeyCode::K => "K",
+            KeyCode::L => "L",
+            KeyCode::Semicolon => ";",
+            KeyCode::Quote => "'",
+            KeyCode::Enter => "Enter",
+            KeyCode::Numpad4 => "Numpad 4",
+            KeyCode::Numpad5 => "Numpad 5",
+            KeyCode::Numpad6 => "Numpad 6",
+            KeyCode::LeftShift => "Left Shift",
+            KeyCode::Z => "Z",
+            KeyCode::X => "X",
+            KeyCode::C => "C",
+            KeyCode::V => "V",
+            KeyCode::B => "B",
+            KeyCode::N => "N",
+            KeyCode::M => "M",
+            KeyCode::Comma => ",",
+            KeyCode::Period => ".",
+            KeyCode::ForwardSlash => "/",
+            KeyCode::RightShift => "Right Shift",
+            KeyCode::UpArrow => "Up",
+            KeyCode::Numpad1 => "Numpad 1",
+            KeyCode::Numpad2 => "Numpad 2",
+            KeyCode::Numpad3 => "Numpad 3",
+            KeyCode::NumpadEnter => "Numpad Enter",
+            KeyCode::LeftControl => "Left Ctrl",
+            KeyCode::LeftSuper => "Left Super",
+            KeyCode::LeftAlt => "Left Alt",
+            KeyCode::Space => "Space",
+            KeyCode::RightAlt => "Right Alt",
+            KeyCode::RightSuper => "Right Super",
+            KeyCode::RightControl => "Right Ctrl",
+            KeyCode::LeftArrow => "Left",
+            KeyCode::DownArrow => "Down",
+            KeyCode::RightArrow => "Right",
+            KeyCode::Numpad0 => "Numpad 0",
+            KeyCode::NumpadDecimal => "Numpad .",
+            KeyCode::F13 => "F13",
+            KeyCode::F14 => "F14",
+            KeyCode::F15 => "F15",
+            KeyCode::F16 => "F16",
+            KeyCode::F17 => "F17",
+            KeyCode::F18 => "F18",
+            KeyCode::F19 => "F19",
+            KeyCode::F20 => "F20",
+            KeyCode::F21 => "F21",
+            KeyCode::F22 => "F22",
+            KeyCode::F23 => "F23",
+            KeyCode::F24 => "F24",
+            KeyCode::F25 => "F25",
+            KeyCode::F26 => "F26",
+            KeyCode::F27 => "F27",
+            KeyCode::F28 => "F28",
+            KeyCode::F29 => "F29",
+            KeyCode::F30 => "F30",
+            KeyCode::F31 => "F31",
+            KeyCode::F32 => "F32",
+            KeyCode::F33 => "F33",
+            KeyCode::F34 => "F34",
+            KeyCode::F35 => "F35",
+            KeyCode::NumpadBackspace => "Numpad Backspace",
+            KeyCode::NumpadClear => "Numpad Clear",
+            KeyCode::NumpadClearEntry => "Numpad Clear Entry",
+            KeyCode::NumpadComma => "Numpad ,",
+            KeyCode::NumpadEquals => "Numpad =",
+            KeyCode::NumpadHash => "Numpad #",
+            KeyCode::NumpadStar => "Numpad *",
+            KeyCode::NumpadLeftParen => "Numpad (",
+            KeyCode::NumpadRightParen => "Numpad )",
+            KeyCode::NumpadMemoryAdd => "Numpad M+",
+            KeyCode::NumpadMemoryClear => "Numpad MC",
+            KeyCode::NumpadMemoryRecall => "Numpad MR",
+            KeyCode::NumpadMemoryStore => "Numpad MS",
+            KeyCode::NumpadMemorySubtract => "Numpad M-",
+            KeyCode::IntlBackslash => "Intl \\",
+            KeyCode::IntlRo => "Ro",
+            KeyCode::IntlYen => "Yen",
+            KeyCode::Convert => "Convert",
+            KeyCode::NonConvert => "Non-Convert",
+            KeyCode::KanaMode => "Kana",
+            KeyCode::Hiragana => "Hiragana",
+            KeyCode::Katakana => "Katakana",
+            KeyCode::Lang1 => "Lang 1",
+            KeyCode::Lang2 => "Lang 2",
+            KeyCode::Lang3 => "Lang 3",
+            KeyCode::Lang4 => "Lang 4",
+            KeyCode::Lang5 => "Lang 5",
+            KeyCode::ContextMenu => "Menu",
+            KeyCode::Fn => "Fn",
+            KeyCode::FnLock => "Fn Lock",
+            KeyCode::Help => "Help",
+            KeyCode::Meta => "Meta",
+            KeyCode::Hyper => "Hyper",
+            KeyCode::Turbo => "Turbo",
+            KeyCode::Abort => "Abort",
+            KeyCode::Resume => "Resume",
+            KeyCode::Suspend => "Suspend",
+            KeyCode::Again => "Again",
+            KeyCode::Copy => "Copy",
+            KeyCode::Cut => "Cut",
+            KeyCode::Paste => "Paste",
+            KeyCode::Find => "Find",
+            KeyCode::Open => "Open",
+            KeyCode::Props => "Props",
+            KeyCode::Select => "Select",
+            KeyCode::Undo => "Undo",
+            KeyCode::MediaPlayPause => "Play / Pause",
+            KeyCode::MediaStop => "Stop",
+            KeyCode::MediaTrackNext => "Next Track",
+            KeyCode::MediaTrackPrevious => "Previous Track",
+            KeyCode::MediaSelect => "Media Select",
+            KeyCode::VolumeDown => "Volume Down",
+            KeyCode::VolumeMute => "Mute",
+            KeyCode::VolumeUp => "Volume Up",
+            KeyCode::BrowserBack => "Browser Back",
+            KeyCode::BrowserForward => "Browser Forward",
+            KeyCode::BrowserRefresh => "Browser Refresh",
+            KeyCode::BrowserStop => "Browser Stop",
+            KeyCode::BrowserSearch => "Browser Search",
+            KeyCode::BrowserFavorites => "Browser Favorites",
+            KeyCode::BrowserHome => "Browser Home",
+            KeyCode::LaunchMail => "Mail",
+            KeyCode::LaunchApp1 => "App 1",
+            KeyCode::LaunchApp2 => "App 2",
+            KeyCode::Eject => "Eject",
+            KeyCode::Power => "Power",
+            KeyCode::Sleep => "Sleep",
+            KeyCode::WakeUp => "Wake Up",
+            KeyCode::Unknown => "Unknown",
+        }
+    }
+}
+
+/// The canonical name of a key code is the name of its variant, such as `"A"`, `"Num1"`, or
+/// `"LeftShift"`.
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parses a key code from its canonical name.  Names are not case-sensitive.
+impl FromStr for KeyCode {
+    type Err = ParseInputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        KeyCode::ALL
+            .iter()
+            .chain([&KeyCode::Unknown])
+            .find(|keycode| keycode.to_string().eq_ignore_ascii_case(text))
+            .copied()
+            .ok_or_else(|| ParseInputError::new("key code", text))
+    }
+}
+
+bitflags! {
+    /// Represents the modifier keys (Shift, Control, Alt, and Super) which are currently held.
+    ///
+    /// Left / right variants of the modifier keys are not distinguished.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+    pub struct Modifiers: u8 {
+        const SHIFT = 0b0001;
+        const CONTROL = 0b0010;
+        const ALT = 0b0100;
+        /// The "Windows" key on most keyboards, or the "Command" key on Apple keyboards.
+        const SUPER = 0b1000;
+    }
+}
+
+impl Modifiers {
+    const NAMES: [(Modifiers, &'static str); 4] = [
+        (Modifiers::CONTROL, "Ctrl"),
+        (Modifiers::SHIFT, "Shift"),
+        (Modifiers::ALT, "Alt"),
+        (Modifiers::SUPER, "Super"),
+    ];
+
+    /// Parse the name of a single modifier key, such as `"Ctrl"`, or `"Shift"`.
+    ///
+    /// Common aliases, such as `"Control"`, `"Option"`, or `"Cmd"` are also accepted.  Names are
+    /// not case-sensitive.
+    pub fn from_key_name(name: &str) -> Option<Modifiers> {
+        match name.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Some(Modifiers::CONTROL),
+            "shift" => Some(Modifiers::SHIFT),
+            "alt" | "option" => Some(Modifiers::ALT),
+            "super" | "cmd" | "command" | "win" => Some(Modifiers::SUPER),
+            _ => None,
+        }
+    }
+
+    /// Get the modifier set by holding a modifier key, such as [`KeyCode::LeftShift`].
+    ///
+    /// Returns `None` for keys which aren't modifier keys.
+    pub fn from_keycode(keycode: KeyCode) -> Option<Modifiers> {
+        match keycode {
+            KeyCode::LeftShift | KeyCode::RightShift => Some(Modifiers::SHIFT),
+            KeyCode::LeftControl | KeyCode::RightControl => Some(Modifiers::CONTROL),
+            KeyCode::LeftAlt | KeyCode::RightAlt => Some(Modifiers::ALT),
+            KeyCode::LeftSuper | KeyCode::RightSuper => Some(Modifiers::SUPER),
+            _ => None,
+        }
+    }
+}
+
+/// Modifiers are written as their names joined by `+`, such as `"Ctrl+Shift"`.  An empty set of
+/// modifiers is written as an empty string.
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = Self::NAMES
+            .iter()
+            .filter(|(modifier, _)| self.contains(*modifier))
+            .map(|(_, name)| *name)
+            .collect::<Vec<_>>();
+        f.pad(&names.join("+"))
+    }
+}
+
+impl FromStr for Modifiers {
+    type Err = ParseInputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.trim().is_empty() {
+            return Ok(Modifiers::empty());
+        }
+        text.split('+')
+            .try_fold(Modifiers::empty(), |modifiers, name| {
+                Modifiers::from_key_name(name.trim())
+                    .map(|modifier| modifiers | modifier)
+                    .ok_or_else(|| ParseInputError::new("set of modifiers", text))
+            })
+    }
+}
+
+/// Learns the current keyboard layout from key presses, and provides layout-aware labels for
+/// physical keys.
+///
+/// The layout is learned as keys are pressed, because most platforms don't provide a way to query
+/// it directly.  Until a key has been pressed, its label falls back to [`KeyCode::label()`].
+/// Only presses without any modifier keys held are learned, so labels aren't affected by Shift,
+/// or other modifiers.
+///
+/// An up-to-date layout is available from [`InputState::keyboard_layout()`](crate::InputState::keyboard_layout).
+#[derive(Debug, Default, Clone)]
+pub struct KeyboardLayout {
+    logical_keys: HashMap<KeyCode, LogicalKey>,
+}
+
+impl KeyboardLayout {
+    /// Learn the logical key of a physical key.
+    pub fn learn(&mut self, key: &Key) {
+        if let (Some(keycode), Some(logical_key)) = (key.keycode, key.logical_key) {
+            self.logical_keys.insert(keycode, logical_key);
+        }
+    }
+
+    /// Get the logical key for a physical key, if it has been learned.
+    pub fn logical_key(&self, keycode: KeyCode) -> Option<LogicalKey> {
+        self.logical_keys.get(&keycode).copied()
+    }
+
+    /// Get a human-readable label for a physical key under the current layout.
+    ///
+    /// This is intended for UI prompts, such as "Press W to move forward."
+    pub fn label(&self, keycode: KeyCode) -> String {
+        match self.logical_key(keycode) {
+            Some(LogicalKey::Character(character)) if !character.is_whitespace() => {
+                character.to_uppercase().collect()
+            }
+            Some(LogicalKey::Named(named)) => named.label().to_string(),
+            _ => keycode.label().to_string(),
+        }
+    }
+}
+
+/// Get the key, and whether or not Shift must be held, to type a character on a US QWERTY
+/// keyboard.
+pub(crate) fn us_keycode(character: char) -> Option<(KeyCode, bool)> {
+    KeyCode::ALL.iter().find_map(|keycode| {
+        let (unshifted, shifted) = us_characters(*keycode)?;
+        if character == unshifted {
+            Some((*keycode, false))
+        } else if character == shifted {
+            Some((*keycode, true))
+        } else {
+            None
+        }
+    })
+}
+
+/// Get the characters a key types on a US QWERTY keyboard, without, and with Shift held.
+pub(crate) fn us_characters(keycode: KeyCode) -> Option<(char, char)> {
+    let label = keycode.label();
+    let mut characters = label.chars();
+    if let (Some(character), None) = (characters.next(), characters.next()) {
+        if character.is_ascii_alphabetic() {
+            return Some((character.to_ascii_lowercase(), character));
+        }
+    }
+    let characters = match keycode {
+        KeyCode::Grave => ('`', '~'),
+        KeyCode::Num1 => ('1', '!'),
+        KeyCode::Num2 => ('2', '@'),
+        KeyCode::Num3 => ('3', '#'),
+        KeyCode::Num4 => ('4', '$'),
+        KeyCode::Num5 => ('5', '%'),
+        KeyCode::Num6 => ('6', '^'),
+        KeyCode::Num7 => ('7', '&'),
+        KeyCode::Num8 => ('8', '*'),
+        KeyCode::Num9 => ('9', '('),
+        KeyCode::Num0 => ('0', ')'),
+        KeyCode::Minus => ('-', '_'),
+        KeyCode::Equals => ('=', '+'),
+        KeyCode::BackSlash => ('\\', '|'),
+        KeyCode::LeftBracket => ('[', '{'),
+        KeyCode::RightBracket => (']', '}'),
+        KeyCode::Semicolon => (';', ':'),
+        KeyCode::Quote => ('\'', '"'),
+        KeyCode::Comma => (',', '<'),
+        KeyCode::Period => ('.', '>'),
+        KeyCode::ForwardSlash => ('/', '?'),
+        KeyCode::Space => (' ', ' '),
+        _ => return None,
+    };
+    Some(characters)
+}
+
+#[cfg(test)]
+mod keyboard_layout_tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn key(keycode: KeyCode, character: char) -> Key {
+        Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: Some(LogicalKey::Character(character)),
+        }
+    }
+
+    #[test]
+    fn should_fall_back_to_us_labels() {
+        let layout = KeyboardLayout::default();
+
+        assert_eq!(layout.label(KeyCode::W), "W");
+        assert_eq!(layout.label(KeyCode::LeftBracket), "[");
+        assert_eq!(layout.label(KeyCode::LeftShift), "Left Shift");
+    }
+
+    #[test]
+    fn should_label_keys_by_learned_layout() {
+        let mut layout = KeyboardLayout::default();
+
+        // AZERTY swaps the positions of W, and Z.
+        layout.learn(&key(KeyCode::W, 'z'));
+        layout.learn(&key(KeyCode::Num2, 'é'));
+
+        assert_eq!(layout.label(KeyCode::W), "Z");
+        assert_eq!(layout.label(KeyCode::Num2), "É");
+        assert_eq!(
+            layout.logical_key(KeyCode::W),
+            Some(LogicalKey::Character('z'))
+        );
+        assert_eq!(
+            layout.label(KeyCode::Z),
+            "Z",
+            "Unlearned keys should use US labels"
+        );
+    }
+
+    #[test]
+    fn should_round_trip_keycodes_through_strings() {
+        for keycode in KeyCode::ALL.iter().chain([&KeyCode::Unknown]) {
+            assert_eq!(keycode.to_string().parse::<KeyCode>(), Ok(*keycode));
+        }
+        assert_eq!("leftshift".parse::<KeyCode>(), Ok(KeyCode::LeftShift));
+        assert!("Shift".parse::<KeyCode>().is_err());
+    }
+
+    #[test_case(Modifiers::empty(), "")]
+    #[test_case(Modifiers::SHIFT, "Shift")]
+    #[test_case(Modifiers::CONTROL | Modifiers::SHIFT, "Ctrl+Shift")]
+    #[test_case(Modifiers::all(), "Ctrl+Shift+Alt+Super")]
+    fn should_round_trip_modifiers_through_strings(modifiers: Modifiers, text: &str) {
+        assert_eq!(modifiers.to_string(), text);
+        assert_eq!(text.parse::<Modifiers>(), Ok(modifiers));
+    }
+
+    #[test]
+    fn should_parse_modifier_aliases() {
+        assert_eq!(
+            "control + cmd".parse::<Modifiers>(),
+            Ok(Modifiers::CONTROL | Modifiers::SUPER)
+        );
+        assert!("Ctrl+S".parse::<Modifiers>().is_err());
+    }
+}
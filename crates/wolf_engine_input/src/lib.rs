@@ -3,25 +3,55 @@
 //! The main job of this crate is to provide a consistent, easy to use, and easy to test, input
 //! system.
 //!
-//! Adaptors for commonly-used APIs, such as Winit, are provided, and can be enabled through their
-//! respective feature flags.
+//! Adaptors for commonly-used APIs, such as Winit, and crossterm, are provided, and can be enabled
+//! through their respective feature flags.  Inputs converted by an adaptor should be passed through
+//! an [`InputState`], which fills in state the adaptor can't know, such as the held modifiers on
+//! Winit key events.
+//!
+//! Keys, buttons, and modifier combinations have a canonical textual form, such as `"Ctrl+Shift+S"`,
+//! or `"Mouse:Left"`, which is provided through their [`Display`](std::fmt::Display), and
+//! [`FromStr`](std::str::FromStr) implementations.  Serde support is available through the `serde`
+//! feature flag.
 
+pub mod axis;
+pub mod combo;
+pub mod context;
+pub mod device;
+pub mod gesture;
 pub mod keyboard;
 pub mod mouse;
+pub mod record;
+pub mod simulator;
+pub mod touch;
+
+mod state;
+pub use state::*;
 
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "serde")]
+mod serde_support;
 #[cfg(feature = "winit")]
 mod winit;
 
-use keyboard::Key;
-use mouse::MouseButton;
+use keyboard::{Key, Modifiers};
+use mouse::{MouseButton, ScrollPhase, ScrollUnit};
+use touch::Touch;
 
 /// Provides a set of common input events.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Input {
     /// A keyboard button was pressed.
     KeyPressed {
         key: Key,
 
+        /// The modifier keys which were held when the key was pressed.
+        ///
+        /// Some adaptors, such as the Winit adaptor, can't know the held modifiers, and leave
+        /// this empty.  Inputs processed by an [`InputState`] always have the correct modifiers.
+        modifiers: Modifiers,
+
         /// Indicates if this is a repeat key press.
         ///
         /// Some platforms repeatedly emit key-down events if a key is held down for a certain
@@ -32,8 +62,34 @@ pub enum Input {
     /// A keyboard button was released
     KeyReleased {
         key: Key,
+
+        /// The modifier keys which were held when the key was released.
+        ///
+        /// Like [`Input::KeyPressed`], this may be empty unless the input was processed by an
+        /// [`InputState`].
+        modifiers: Modifiers,
+    },
+
+    /// The set of held modifier keys has changed.
+    ModifiersChanged {
+        modifiers: Modifiers,
     },
 
+    /// The mouse cursor has entered the window.
+    CursorEntered,
+
+    /// The mouse cursor has left the window.
+    CursorLeft,
+
+    /// The window has gained keyboard focus.
+    FocusGained,
+
+    /// The window has lost keyboard focus.
+    ///
+    /// Keys, and buttons released after this point may not be reported.  See
+    /// [`InputState`] for a way to keep held inputs from getting stuck.
+    FocusLost,
+
     /// The mouse has moved.
     ///
     /// This event indicates the mouse has moved to a specific point in the window.
@@ -61,9 +117,38 @@ pub enum Input {
     },
 
     /// The mouse was scrolled.
+    ///
+    /// Positive values indicate the content should move right / down.  See
+    /// [`ScrollNormalizer`](mouse::ScrollNormalizer) for converting between units.
     MouseScrolled {
         delta_x: f32,
         delta_y: f32,
+
+        /// The unit `delta_x`, and `delta_y` are measured in.
+        unit: ScrollUnit,
+
+        /// The phase of the scroll gesture.
+        phase: ScrollPhase,
+    },
+
+    /// A finger has started touching the screen.
+    TouchStarted {
+        touch: Touch,
+    },
+
+    /// A finger touching the screen has moved.
+    TouchMoved {
+        touch: Touch,
+    },
+
+    /// A finger has been lifted from the screen.
+    TouchEnded {
+        touch: Touch,
+    },
+
+    /// A touch was cancelled by the system, and should not be treated as a deliberate action.
+    TouchCancelled {
+        touch: Touch,
     },
 }
 
@@ -77,3 +162,27 @@ pub trait ToInput {
     /// Convert a reference to `self` to an [`Input`], if possible.
     fn to_input(&self) -> Option<Input>;
 }
+
+/// An error indicating a string could not be parsed into an input type.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseInputError {
+    expected: &'static str,
+    text: String,
+}
+
+impl ParseInputError {
+    pub(crate) fn new(expected: &'static str, text: &str) -> Self {
+        Self {
+            expected,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for ParseInputError {}
+
+impl std::fmt::Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" is not a valid {}.", self.text, self.expected)
+    }
+}
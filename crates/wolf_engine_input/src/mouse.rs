@@ -1,5 +1,9 @@
 //! Provides types related to mouse input.
 
+use std::{fmt, str::FromStr};
+
+use crate::{Input, ParseInputError};
+
 /// Identifies a mouse button.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MouseButton {
@@ -10,3 +14,202 @@ pub enum MouseButton {
     Back,
     Other(u32),
 }
+
+/// Mouse buttons are written with a `Mouse:` prefix, such as `"Mouse:Left"`, or `"Mouse:7"` for
+/// [`MouseButton::Other`] buttons.
+impl fmt::Display for MouseButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MouseButton::Other(number) => write!(f, "Mouse:{number}"),
+            button => write!(f, "Mouse:{button:?}"),
+        }
+    }
+}
+
+/// Parses a mouse button from its canonical form.  Names are not case-sensitive.
+impl FromStr for MouseButton {
+    type Err = ParseInputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let name = text
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("mouse:"))
+            .map(|_| &text[6..])
+            .ok_or_else(|| ParseInputError::new("mouse button", text))?;
+        match name.to_ascii_lowercase().as_str() {
+            "left" => Ok(MouseButton::Left),
+            "middle" => Ok(MouseButton::Middle),
+            "right" => Ok(MouseButton::Right),
+            "forward" => Ok(MouseButton::Forward),
+            "back" => Ok(MouseButton::Back),
+            number => number
+                .parse()
+                .map(MouseButton::Other)
+                .map_err(|_| ParseInputError::new("mouse button", text)),
+        }
+    }
+}
+
+/// The unit of a scroll delta.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollUnit {
+    /// The delta is measured in lines, or rows.  This is typical of a traditional mouse wheel.
+    Lines,
+    /// The delta is measured in pixels.  This is typical of touchpads, and other
+    /// high-resolution scrolling devices.
+    Pixels,
+}
+
+/// The phase of a scroll gesture.
+///
+/// Devices which don't have a concept of a scroll gesture, such as a traditional mouse wheel,
+/// always report [`ScrollPhase::Moved`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollPhase {
+    Started,
+    Moved,
+    Ended,
+    Cancelled,
+}
+
+/// Converts scroll deltas between [`ScrollUnit`]s, using a configurable line height.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_input::{Input, mouse::{ScrollNormalizer, ScrollPhase, ScrollUnit}};
+/// #
+/// let normalizer = ScrollNormalizer::new(16.0);
+/// let input = Input::MouseScrolled {
+///     delta_x: 0.0,
+///     delta_y: 32.0,
+///     unit: ScrollUnit::Pixels,
+///     phase: ScrollPhase::Moved,
+/// };
+///
+/// assert_eq!(
+///     normalizer.normalize(input, ScrollUnit::Lines),
+///     Input::MouseScrolled {
+///         delta_x: 0.0,
+///         delta_y: 2.0,
+///         unit: ScrollUnit::Lines,
+///         phase: ScrollPhase::Moved,
+///     },
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScrollNormalizer {
+    line_height: f32,
+}
+
+impl ScrollNormalizer {
+    /// Create a new normalizer, with the height of one line in pixels.
+    pub fn new(line_height: f32) -> Self {
+        Self { line_height }
+    }
+
+    /// Get the height of one line in pixels.
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Convert a `(delta_x, delta_y)` scroll delta from one unit to another.
+    pub fn convert(&self, delta: (f32, f32), from: ScrollUnit, to: ScrollUnit) -> (f32, f32) {
+        match (from, to) {
+            (ScrollUnit::Lines, ScrollUnit::Pixels) => {
+                (delta.0 * self.line_height, delta.1 * self.line_height)
+            }
+            (ScrollUnit::Pixels, ScrollUnit::Lines) => {
+                (delta.0 / self.line_height, delta.1 / self.line_height)
+            }
+            _ => delta,
+        }
+    }
+
+    /// Convert an [`Input::MouseScrolled`] to the given unit.
+    ///
+    /// All other inputs are returned unchanged.
+    pub fn normalize(&self, input: Input, unit: ScrollUnit) -> Input {
+        match input {
+            Input::MouseScrolled {
+                delta_x,
+                delta_y,
+                unit: from,
+                phase,
+            } => {
+                let (delta_x, delta_y) = self.convert((delta_x, delta_y), from, unit);
+                Input::MouseScrolled {
+                    delta_x,
+                    delta_y,
+                    unit,
+                    phase,
+                }
+            }
+            _ => input,
+        }
+    }
+}
+
+impl Default for ScrollNormalizer {
+    /// Create a normalizer with a line height of 20 pixels.
+    fn default() -> Self {
+        Self::new(20.0)
+    }
+}
+
+#[cfg(test)]
+mod scroll_normalizer_tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(ScrollUnit::Lines, ScrollUnit::Pixels, (1.0, -2.0), (10.0, -20.0))]
+    #[test_case(ScrollUnit::Pixels, ScrollUnit::Lines, (15.0, -30.0), (1.5, -3.0))]
+    #[test_case(ScrollUnit::Lines, ScrollUnit::Lines, (1.0, -2.0), (1.0, -2.0))]
+    #[test_case(ScrollUnit::Pixels, ScrollUnit::Pixels, (15.0, -30.0), (15.0, -30.0))]
+    fn should_convert_between_units(
+        from: ScrollUnit,
+        to: ScrollUnit,
+        delta: (f32, f32),
+        expected: (f32, f32),
+    ) {
+        let normalizer = ScrollNormalizer::new(10.0);
+
+        assert_eq!(normalizer.convert(delta, from, to), expected);
+    }
+
+    #[test]
+    fn should_not_change_other_inputs() {
+        let normalizer = ScrollNormalizer::default();
+        let input = Input::MouseMoved {
+            delta_x: 1.0,
+            delta_y: 1.0,
+        };
+
+        assert_eq!(normalizer.normalize(input, ScrollUnit::Lines), input);
+    }
+}
+
+#[cfg(test)]
+mod mouse_button_tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(MouseButton::Left, "Mouse:Left")]
+    #[test_case(MouseButton::Back, "Mouse:Back")]
+    #[test_case(MouseButton::Other(7), "Mouse:7")]
+    fn should_round_trip_mouse_buttons_through_strings(button: MouseButton, text: &str) {
+        assert_eq!(button.to_string(), text);
+        assert_eq!(text.parse::<MouseButton>(), Ok(button));
+    }
+
+    #[test_case("Left")]
+    #[test_case("Mouse:")]
+    #[test_case("Mouse:Sideways")]
+    fn should_reject_invalid_mouse_buttons(text: &str) {
+        assert!(text.parse::<MouseButton>().is_err());
+    }
+}
@@ -0,0 +1,568 @@
+//! Provides input recording, and deterministic playback.
+//!
+//! Recordings are useful for replays, and for automated, input-driven tests.  An
+//! [`InputRecorder`] captures inputs along with the frame, and time they happened on, and can save
+//! them to a file.  An [`InputPlayback`] loads the recording, and hands the inputs back, frame
+//! by frame.
+//!
+//! Played-back inputs implement [`ToInput`], so they can be fed through the same path as inputs
+//! from any other adapter.  To reproduce a session exactly, record inputs as they come out of
+//! [`ToInput`], before any further processing, such as by an [`InputState`](crate::InputState).
+//!
+//! # Examples
+//!
+//! ```
+//! # use wolf_engine_input::{Input, ToInput, record::{InputPlayback, InputRecorder}};
+//! #
+//! let mut recorder = InputRecorder::new();
+//! recorder.record(Input::MouseMovedTo { x: 10.0, y: 20.0 });
+//! recorder.next_frame();
+//! recorder.record(Input::FocusLost);
+//!
+//! let mut file = Vec::new();
+//! recorder.write_to(&mut file).unwrap();
+//!
+//! let mut playback = InputPlayback::read_from(file.as_slice()).unwrap();
+//! let first_frame: Vec<Input> = playback
+//!     .next_frame()
+//!     .iter()
+//!     .filter_map(|recorded_input| recorded_input.to_input())
+//!     .collect();
+//! assert_eq!(first_frame, vec![Input::MouseMovedTo { x: 10.0, y: 20.0 }]);
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    str::SplitWhitespace,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    keyboard::{Key, KeyCode, LogicalKey, Modifiers},
+    mouse::{MouseButton, ScrollPhase, ScrollUnit},
+    touch::Touch,
+    Input, ToInput,
+};
+
+/// The first line of every recording file.
+const HEADER: &str = "wolf_engine_input recording v1";
+
+/// An [`Input`], along with the frame, and time it was recorded on.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RecordedInput {
+    /// The frame the input happened on, counting from 0.
+    pub frame: u64,
+    /// The time the input happened, relative to the start of the recording.
+    pub timestamp: Duration,
+    pub input: Input,
+}
+
+impl ToInput for RecordedInput {
+    fn to_input(&self) -> Option<Input> {
+        Some(self.input)
+    }
+}
+
+/// Captures inputs, so they can be saved, and played back later.
+#[derive(Debug, Clone)]
+pub struct InputRecorder {
+    frame: u64,
+    start_time: Instant,
+    inputs: Vec<RecordedInput>,
+}
+
+impl InputRecorder {
+    /// Create a new recorder, starting at frame 0, and the current time.
+    pub fn new() -> Self {
+        Self {
+            frame: 0,
+            start_time: Instant::now(),
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Get the current frame.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Advance to the next frame.
+    ///
+    /// This should be called once per frame, such as when
+    /// `WindowEvent::EventsCleared` is received.
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Record an input on the current frame, timestamped with the time since the recording began.
+    pub fn record(&mut self, input: Input) {
+        self.record_at(self.start_time.elapsed(), input);
+    }
+
+    /// Record an input on the current frame, with a specific timestamp.
+    pub fn record_at(&mut self, timestamp: Duration, input: Input) {
+        self.inputs.push(RecordedInput {
+            frame: self.frame,
+            timestamp,
+            input,
+        });
+    }
+
+    /// Get all inputs recorded so far.
+    pub fn inputs(&self) -> &[RecordedInput] {
+        &self.inputs
+    }
+
+    /// Write the recording to a writer.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{HEADER}")?;
+        for recorded_input in &self.inputs {
+            writeln!(
+                writer,
+                "{} {} {}",
+                recorded_input.frame,
+                recorded_input.timestamp.as_nanos(),
+                encode_input(&recorded_input.input),
+            )?;
+        }
+        writer.flush()
+    }
+
+    /// Save the recording to a file, replacing it if it already exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl Default for InputRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Plays back recorded inputs, frame by frame.
+#[derive(Debug, Clone)]
+pub struct InputPlayback {
+    frame: u64,
+    inputs: VecDeque<RecordedInput>,
+}
+
+impl InputPlayback {
+    /// Create a playback of recorded inputs, starting at frame 0.
+    ///
+    /// The inputs are expected to be in the order they were recorded in.
+    pub fn new(inputs: Vec<RecordedInput>) -> Self {
+        Self {
+            frame: 0,
+            inputs: inputs.into(),
+        }
+    }
+
+    /// Read a recording written by [`InputRecorder::write_to()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`io::ErrorKind::InvalidData`] if the recording is malformed.
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        match lines.next() {
+            Some(Ok(header)) if header == HEADER => (),
+            Some(Err(error)) => return Err(error),
+            _ => return Err(invalid_data("missing recording header".to_string())),
+        }
+
+        let mut inputs = Vec::new();
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let recorded_input = decode_recorded_input(&line)
+                .map_err(|error| invalid_data(format!("line {}: {error}", index + 2)))?;
+            inputs.push(recorded_input);
+        }
+        Ok(Self::new(inputs))
+    }
+
+    /// Load a recording saved by [`InputRecorder::save()`].
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Get the current frame.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns true if all recorded inputs have been played back.
+    pub fn is_finished(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Get the inputs recorded on the current frame, then advance to the next frame.
+    pub fn next_frame(&mut self) -> Vec<RecordedInput> {
+        let mut inputs = Vec::new();
+        while let Some(recorded_input) = self.inputs.front() {
+            if recorded_input.frame > self.frame {
+                break;
+            }
+            inputs.extend(self.inputs.pop_front());
+        }
+        self.frame += 1;
+        inputs
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn encode_input(input: &Input) -> String {
+    match input {
+        Input::KeyPressed {
+            key,
+            modifiers,
+            is_repeat,
+        } => format!(
+            "KeyPressed {} {} {is_repeat}",
+            encode_key(key),
+            modifiers.bits()
+        ),
+        Input::KeyReleased { key, modifiers } => {
+            format!("KeyReleased {} {}", encode_key(key), modifiers.bits())
+        }
+        Input::ModifiersChanged { modifiers } => format!("ModifiersChanged {}", modifiers.bits()),
+        Input::CursorEntered => "CursorEntered".to_string(),
+        Input::CursorLeft => "CursorLeft".to_string(),
+        Input::FocusGained => "FocusGained".to_string(),
+        Input::FocusLost => "FocusLost".to_string(),
+        Input::MouseMovedTo { x, y } => format!("MouseMovedTo {x} {y}"),
+        Input::MouseMoved { delta_x, delta_y } => format!("MouseMoved {delta_x} {delta_y}"),
+        Input::MouseButtonPressed { button } => {
+            format!("MouseButtonPressed {}", encode_mouse_button(button))
+        }
+        Input::MouseButtonReleased { button } => {
+            format!("MouseButtonReleased {}", encode_mouse_button(button))
+        }
+        Input::MouseScrolled {
+            delta_x,
+            delta_y,
+            unit,
+            phase,
+        } => format!("MouseScrolled {delta_x} {delta_y} {unit:?} {phase:?}"),
+        Input::TouchStarted { touch } => format!("TouchStarted {}", encode_touch(touch)),
+        Input::TouchMoved { touch } => format!("TouchMoved {}", encode_touch(touch)),
+        Input::TouchEnded { touch } => format!("TouchEnded {}", encode_touch(touch)),
+        Input::TouchCancelled { touch } => format!("TouchCancelled {}", encode_touch(touch)),
+    }
+}
+
+fn encode_key(key: &Key) -> String {
+    let keycode = match key.keycode {
+        Some(keycode) => keycode.to_string(),
+        None => "-".to_string(),
+    };
+    let logical_key = match key.logical_key {
+        Some(LogicalKey::Character(character)) => format!("c:{}", character as u32),
+        Some(LogicalKey::Named(keycode)) => format!("n:{keycode}"),
+        None => "-".to_string(),
+    };
+    format!("{} {keycode} {logical_key}", key.scancode)
+}
+
+fn encode_mouse_button(button: &MouseButton) -> String {
+    match button {
+        MouseButton::Other(number) => format!("Other:{number}"),
+        button => format!("{button:?}"),
+    }
+}
+
+fn encode_touch(touch: &Touch) -> String {
+    let pressure = match touch.pressure {
+        Some(pressure) => pressure.to_string(),
+        None => "-".to_string(),
+    };
+    format!("{} {} {} {pressure}", touch.finger_id, touch.x, touch.y)
+}
+
+fn decode_recorded_input(line: &str) -> Result<RecordedInput, String> {
+    let mut tokens = line.split_whitespace();
+    let frame = parse_next(&mut tokens, "frame")?;
+    let timestamp = Duration::from_nanos(parse_next(&mut tokens, "timestamp")?);
+    let input = decode_input(&mut tokens)?;
+    match tokens.next() {
+        Some(token) => Err(format!("unexpected token: {token}")),
+        None => Ok(RecordedInput {
+            frame,
+            timestamp,
+            input,
+        }),
+    }
+}
+
+fn decode_input(tokens: &mut SplitWhitespace) -> Result<Input, String> {
+    let input = match next_token(tokens, "input")? {
+        "KeyPressed" => Input::KeyPressed {
+            key: decode_key(tokens)?,
+            modifiers: decode_modifiers(tokens)?,
+            is_repeat: parse_next(tokens, "is_repeat")?,
+        },
+        "KeyReleased" => Input::KeyReleased {
+            key: decode_key(tokens)?,
+            modifiers: decode_modifiers(tokens)?,
+        },
+        "ModifiersChanged" => Input::ModifiersChanged {
+            modifiers: decode_modifiers(tokens)?,
+        },
+        "CursorEntered" => Input::CursorEntered,
+        "CursorLeft" => Input::CursorLeft,
+        "FocusGained" => Input::FocusGained,
+        "FocusLost" => Input::FocusLost,
+        "MouseMovedTo" => Input::MouseMovedTo {
+            x: parse_next(tokens, "x")?,
+            y: parse_next(tokens, "y")?,
+        },
+        "MouseMoved" => Input::MouseMoved {
+            delta_x: parse_next(tokens, "delta_x")?,
+            delta_y: parse_next(tokens, "delta_y")?,
+        },
+        "MouseButtonPressed" => Input::MouseButtonPressed {
+            button: decode_mouse_button(tokens)?,
+        },
+        "MouseButtonReleased" => Input::MouseButtonReleased {
+            button: decode_mouse_button(tokens)?,
+        },
+        "MouseScrolled" => Input::MouseScrolled {
+            delta_x: parse_next(tokens, "delta_x")?,
+            delta_y: parse_next(tokens, "delta_y")?,
+            unit: match next_token(tokens, "unit")? {
+                "Lines" => ScrollUnit::Lines,
+                "Pixels" => ScrollUnit::Pixels,
+                unit => return Err(format!("unknown scroll unit: {unit}")),
+            },
+            phase: match next_token(tokens, "phase")? {
+                "Started" => ScrollPhase::Started,
+                "Moved" => ScrollPhase::Moved,
+                "Ended" => ScrollPhase::Ended,
+                "Cancelled" => ScrollPhase::Cancelled,
+                phase => return Err(format!("unknown scroll phase: {phase}")),
+            },
+        },
+        "TouchStarted" => Input::TouchStarted {
+            touch: decode_touch(tokens)?,
+        },
+        "TouchMoved" => Input::TouchMoved {
+            touch: decode_touch(tokens)?,
+        },
+        "TouchEnded" => Input::TouchEnded {
+            touch: decode_touch(tokens)?,
+        },
+        "TouchCancelled" => Input::TouchCancelled {
+            touch: decode_touch(tokens)?,
+        },
+        input => return Err(format!("unknown input: {input}")),
+    };
+    Ok(input)
+}
+
+fn decode_key(tokens: &mut SplitWhitespace) -> Result<Key, String> {
+    let scancode = parse_next(tokens, "scancode")?;
+    let keycode = match next_token(tokens, "keycode")? {
+        "-" => None,
+        name => Some(decode_keycode(name)?),
+    };
+    let logical_key = match next_token(tokens, "logical_key")? {
+        "-" => None,
+        token => Some(match token.split_once(':') {
+            Some(("c", codepoint)) => codepoint
+                .parse()
+                .ok()
+                .and_then(char::from_u32)
+                .map(LogicalKey::Character)
+                .ok_or_else(|| format!("invalid character: {codepoint}"))?,
+            Some(("n", name)) => LogicalKey::Named(decode_keycode(name)?),
+            _ => return Err(format!("invalid logical key: {token}")),
+        }),
+    };
+    Ok(Key {
+        scancode,
+        keycode,
+        logical_key,
+    })
+}
+
+fn decode_keycode(name: &str) -> Result<KeyCode, String> {
+    name.parse::<KeyCode>().map_err(|error| error.to_string())
+}
+
+fn decode_modifiers(tokens: &mut SplitWhitespace) -> Result<Modifiers, String> {
+    let bits = parse_next(tokens, "modifiers")?;
+    Modifiers::from_bits(bits).ok_or_else(|| format!("invalid modifiers: {bits}"))
+}
+
+fn decode_mouse_button(tokens: &mut SplitWhitespace) -> Result<MouseButton, String> {
+    let button = match next_token(tokens, "button")? {
+        "Left" => MouseButton::Left,
+        "Middle" => MouseButton::Middle,
+        "Right" => MouseButton::Right,
+        "Forward" => MouseButton::Forward,
+        "Back" => MouseButton::Back,
+        token => match token.strip_prefix("Other:").map(str::parse) {
+            Some(Ok(number)) => MouseButton::Other(number),
+            _ => return Err(format!("unknown mouse button: {token}")),
+        },
+    };
+    Ok(button)
+}
+
+fn decode_touch(tokens: &mut SplitWhitespace) -> Result<Touch, String> {
+    Ok(Touch {
+        finger_id: parse_next(tokens, "finger_id")?,
+        x: parse_next(tokens, "x")?,
+        y: parse_next(tokens, "y")?,
+        pressure: match next_token(tokens, "pressure")? {
+            "-" => None,
+            pressure => Some(
+                pressure
+                    .parse()
+                    .map_err(|_| format!("invalid pressure: {pressure}"))?,
+            ),
+        },
+    })
+}
+
+fn next_token<'a>(tokens: &mut SplitWhitespace<'a>, name: &str) -> Result<&'a str, String> {
+    tokens.next().ok_or_else(|| format!("missing {name}"))
+}
+
+fn parse_next<T: std::str::FromStr>(tokens: &mut SplitWhitespace, name: &str) -> Result<T, String> {
+    let token = next_token(tokens, name)?;
+    token
+        .parse()
+        .map_err(|_| format!("invalid {name}: {token}"))
+}
+
+#[cfg(test)]
+mod record_tests {
+    use super::*;
+
+    fn all_inputs() -> Vec<Input> {
+        let key = Key {
+            scancode: 17,
+            keycode: Some(KeyCode::W),
+            logical_key: Some(LogicalKey::Character('z')),
+        };
+        let touch = Touch {
+            finger_id: 3,
+            x: 0.1,
+            y: 1.0 / 3.0,
+            pressure: Some(0.75),
+        };
+        vec![
+            Input::KeyPressed {
+                key,
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                is_repeat: true,
+            },
+            Input::KeyReleased {
+                key: Key {
+                    scancode: 0,
+                    keycode: None,
+                    logical_key: Some(LogicalKey::Named(KeyCode::Enter)),
+                },
+                modifiers: Modifiers::empty(),
+            },
+            Input::ModifiersChanged {
+                modifiers: Modifiers::all(),
+            },
+            Input::CursorEntered,
+            Input::CursorLeft,
+            Input::FocusGained,
+            Input::FocusLost,
+            Input::MouseMovedTo { x: 12.5, y: -3.0 },
+            Input::MouseMoved {
+                delta_x: 0.001,
+                delta_y: 1e10,
+            },
+            Input::MouseButtonPressed {
+                button: MouseButton::Other(7),
+            },
+            Input::MouseButtonReleased {
+                button: MouseButton::Back,
+            },
+            Input::MouseScrolled {
+                delta_x: 0.0,
+                delta_y: -1.5,
+                unit: ScrollUnit::Pixels,
+                phase: ScrollPhase::Started,
+            },
+            Input::TouchStarted { touch },
+            Input::TouchMoved { touch },
+            Input::TouchEnded {
+                touch: Touch {
+                    pressure: None,
+                    ..touch
+                },
+            },
+            Input::TouchCancelled { touch },
+        ]
+    }
+
+    #[test]
+    fn should_round_trip_all_inputs() {
+        let mut recorder = InputRecorder::new();
+        for (index, input) in all_inputs().into_iter().enumerate() {
+            recorder.record_at(Duration::from_millis(index as u64 * 16), input);
+            recorder.next_frame();
+        }
+
+        let mut file = Vec::new();
+        recorder.write_to(&mut file).unwrap();
+        let mut playback = InputPlayback::read_from(file.as_slice()).unwrap();
+
+        let mut played_back = Vec::new();
+        while !playback.is_finished() {
+            played_back.extend(playback.next_frame());
+        }
+        assert_eq!(played_back, recorder.inputs());
+    }
+
+    #[test]
+    fn should_play_back_inputs_on_their_recorded_frames() {
+        let mut recorder = InputRecorder::new();
+        recorder.record(Input::FocusGained);
+        recorder.record(Input::CursorEntered);
+        recorder.next_frame();
+        recorder.next_frame();
+        recorder.record(Input::FocusLost);
+
+        let mut playback = InputPlayback::new(recorder.inputs().to_vec());
+        let mut frame = || -> Vec<Input> {
+            playback
+                .next_frame()
+                .iter()
+                .filter_map(ToInput::to_input)
+                .collect()
+        };
+
+        assert_eq!(frame(), vec![Input::FocusGained, Input::CursorEntered]);
+        assert_eq!(frame(), vec![]);
+        assert_eq!(frame(), vec![Input::FocusLost]);
+        assert_eq!(frame(), vec![]);
+    }
+
+    #[test]
+    fn should_reject_malformed_recordings() {
+        let missing_header = "0 0 FocusLost\n";
+        let unknown_input = format!("{HEADER}\n0 0 Jump\n");
+        let missing_field = format!("{HEADER}\n0 0 MouseMovedTo 1.0\n");
+
+        for recording in [missing_header, &unknown_input, &missing_field] {
+            let error = InputPlayback::read_from(recording.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
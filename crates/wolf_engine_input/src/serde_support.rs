@@ -0,0 +1,98 @@
+//! Provides Serde support for types which are serialized in their canonical textual form.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    combo::{ComboButton, ComboStep},
+    keyboard::{KeyCode, Modifiers},
+    mouse::MouseButton,
+};
+
+macro_rules! impl_serde_as_string {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Serialize for $type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_as_string!(KeyCode, Modifiers, MouseButton, ComboButton, ComboStep);
+
+#[cfg(test)]
+mod serde_tests {
+    use crate::{
+        keyboard::{Key, LogicalKey},
+        mouse::{ScrollPhase, ScrollUnit},
+        touch::Touch,
+        Input,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_serialize_buttons_as_strings() {
+        let step =
+            ComboStep::from(KeyCode::S).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT);
+
+        assert_eq!(serde_json::to_string(&step).unwrap(), "\"Ctrl+Shift+S\"");
+        assert_eq!(
+            serde_json::to_string(&MouseButton::Left).unwrap(),
+            "\"Mouse:Left\""
+        );
+        assert_eq!(
+            serde_json::from_str::<ComboStep>("\"ctrl+shift+s\"").unwrap(),
+            step
+        );
+        assert!(serde_json::from_str::<KeyCode>("\"NotAKey\"").is_err());
+    }
+
+    #[test]
+    fn should_round_trip_inputs() {
+        let inputs = [
+            Input::KeyPressed {
+                key: Key {
+                    scancode: 17,
+                    keycode: Some(KeyCode::W),
+                    logical_key: Some(LogicalKey::Character('z')),
+                },
+                modifiers: Modifiers::SHIFT,
+                is_repeat: true,
+            },
+            Input::MouseButtonReleased {
+                button: MouseButton::Other(9),
+            },
+            Input::MouseScrolled {
+                delta_x: 0.0,
+                delta_y: -1.5,
+                unit: ScrollUnit::Lines,
+                phase: ScrollPhase::Moved,
+            },
+            Input::TouchStarted {
+                touch: Touch {
+                    finger_id: 3,
+                    x: 10.0,
+                    y: 20.0,
+                    pressure: None,
+                },
+            },
+            Input::FocusLost,
+        ];
+
+        for input in inputs {
+            let json = serde_json::to_string(&input).unwrap();
+            assert_eq!(serde_json::from_str::<Input>(&json).unwrap(), input);
+        }
+    }
+}
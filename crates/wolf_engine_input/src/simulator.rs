@@ -0,0 +1,489 @@
+//! Provides simulated input, for testing input-driven code without a real window.
+//!
+//! An [`InputSimulator`] builds a timeline of [`Input`]s from high-level actions, such as typing a
+//! string, holding a key, or clicking the mouse.  The timeline is then played back, either
+//! directly to a handler, or into an [`EventSender`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use wolf_engine_input::{keyboard::KeyCode, mouse::MouseButton, simulator::InputSimulator};
+//! #
+//! let mut simulator = InputSimulator::default();
+//! simulator
+//!     .hold_key(KeyCode::W, Duration::from_millis(500))
+//!     .move_mouse_to(100.0, 50.0)
+//!     .click(MouseButton::Left);
+//!
+//! // Play back everything which has happened in the first 100ms.
+//! let mut inputs = Vec::new();
+//! simulator.play_due(Duration::from_millis(100), |input| inputs.push(input));
+//!
+//! assert_eq!(inputs.len(), 1);
+//! ```
+//!
+//! Simulated inputs can also be injected into a window system's event queue as window events,
+//! using [`InputSimulator::send_due()`], or [`InputSimulator::send_all()`].
+
+use std::time::Duration;
+
+use wolf_engine_events::{
+    dynamic::{AnyEvent, Event},
+    EventSender, ReceiverDroppedError,
+};
+
+use crate::{
+    keyboard::{self, Key, KeyCode, LogicalKey, Modifiers},
+    mouse::{MouseButton, ScrollPhase, ScrollUnit},
+    Input,
+};
+
+/// Builds, and plays back a timeline of simulated [`Input`]s.
+///
+/// Actions are added at the simulator's current time, which starts at zero, and is only moved
+/// forward by [`InputSimulator::wait()`], or actions which take time, such as
+/// [`InputSimulator::hold_key()`].
+///
+/// Keys are simulated as if they were on a US QWERTY keyboard.  The held modifier keys are
+/// tracked, and reported on key events, the same way an [`InputState`](crate::InputState) would.
+#[derive(Debug, Default, Clone)]
+pub struct InputSimulator {
+    timeline: Vec<(Duration, Input)>,
+    time: Duration,
+    next_input: usize,
+    held_modifier_keys: Vec<KeyCode>,
+}
+
+impl InputSimulator {
+    /// Get the current time of the timeline.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Get the full timeline of simulated inputs, and the times they happen.
+    pub fn inputs(&self) -> &[(Duration, Input)] {
+        &self.timeline
+    }
+
+    /// Returns true if all simulated inputs have been played back.
+    pub fn is_finished(&self) -> bool {
+        self.next_input >= self.timeline.len()
+    }
+
+    /// Move the current time forward.
+    pub fn wait(&mut self, duration: Duration) -> &mut Self {
+        self.time += duration;
+        self
+    }
+
+    /// Add a raw input at the current time.
+    pub fn input(&mut self, input: Input) -> &mut Self {
+        self.timeline.push((self.time, input));
+        self
+    }
+
+    /// Press a key, and keep it held.
+    pub fn press_key(&mut self, keycode: KeyCode) -> &mut Self {
+        let input = Input::KeyPressed {
+            key: self.key(keycode),
+            modifiers: self.modifiers(),
+            is_repeat: false,
+        };
+        self.input(input);
+        self.set_modifier(keycode, true)
+    }
+
+    /// Release a held key.
+    pub fn release_key(&mut self, keycode: KeyCode) -> &mut Self {
+        self.set_modifier(keycode, false);
+        let input = Input::KeyReleased {
+            key: self.key(keycode),
+            modifiers: self.modifiers(),
+        };
+        self.input(input)
+    }
+
+    /// Press, and immediately release a key.
+    pub fn tap_key(&mut self, keycode: KeyCode) -> &mut Self {
+        self.press_key(keycode).release_key(keycode)
+    }
+
+    /// Press a key, hold it for a duration, then release it.
+    pub fn hold_key(&mut self, keycode: KeyCode, duration: Duration) -> &mut Self {
+        self.press_key(keycode).wait(duration).release_key(keycode)
+    }
+
+    /// Type a string, one key tap at a time.
+    ///
+    /// Shift is held for characters which need it.  Characters which can't be typed on a US
+    /// QWERTY keyboard are sent as key taps with only a logical key.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for character in text.chars() {
+            match keyboard::us_keycode(character) {
+                Some((keycode, true)) if !self.modifiers().contains(Modifiers::SHIFT) => {
+                    self.press_key(KeyCode::LeftShift)
+                        .tap_key(keycode)
+                        .release_key(KeyCode::LeftShift);
+                }
+                Some((keycode, _)) => {
+                    self.tap_key(keycode);
+                }
+                None => {
+                    let key = Key {
+                        scancode: 0,
+                        keycode: None,
+                        logical_key: Some(LogicalKey::Character(character)),
+                    };
+                    let modifiers = self.modifiers();
+                    self.input(Input::KeyPressed {
+                        key,
+                        modifiers,
+                        is_repeat: false,
+                    })
+                    .input(Input::KeyReleased { key, modifiers });
+                }
+            }
+        }
+        self
+    }
+
+    /// Move the mouse to a point in the window.
+    pub fn move_mouse_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.input(Input::MouseMovedTo { x, y })
+    }
+
+    /// Press a mouse button, and keep it held.
+    pub fn press_mouse(&mut self, button: MouseButton) -> &mut Self {
+        self.input(Input::MouseButtonPressed { button })
+    }
+
+    /// Release a held mouse button.
+    pub fn release_mouse(&mut self, button: MouseButton) -> &mut Self {
+        self.input(Input::MouseButtonReleased { button })
+    }
+
+    /// Press, and immediately release a mouse button.
+    pub fn click(&mut self, button: MouseButton) -> &mut Self {
+        self.press_mouse(button).release_mouse(button)
+    }
+
+    /// Scroll the mouse wheel by a number of lines.
+    pub fn scroll(&mut self, delta_x: f32, delta_y: f32) -> &mut Self {
+        self.input(Input::MouseScrolled {
+            delta_x,
+            delta_y,
+            unit: ScrollUnit::Lines,
+            phase: ScrollPhase::Moved,
+        })
+    }
+
+    /// Play back all inputs which happen at, or before `time`, and haven't been played yet.
+    pub fn play_due<F: FnMut(Input)>(&mut self, time: Duration, mut handler: F) {
+        while let Some((input_time, input)) = self.timeline.get(self.next_input) {
+            if *input_time > time {
+                break;
+            }
+            handler(*input);
+            self.next_input += 1;
+        }
+    }
+
+    /// Send all inputs which happen at, or before `time`, and haven't been sent yet.
+    ///
+    /// Each input is converted to an event of type `E`, such as `WindowEvent::Input`, before it is
+    /// sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the receiver has been dropped.  Inputs which could not be sent are not
+    /// played back.
+    pub fn send_due<E, S>(
+        &mut self,
+        time: Duration,
+        event_sender: &S,
+    ) -> Result<(), ReceiverDroppedError>
+    where
+        E: Event + From<Input>,
+        S: EventSender<AnyEvent> + ?Sized,
+    {
+        while let Some((input_time, input)) = self.timeline.get(self.next_input) {
+            if *input_time > time {
+                break;
+            }
+            event_sender.send_event(Box::new(E::from(*input)))?;
+            self.next_input += 1;
+        }
+        Ok(())
+    }
+
+    /// Send all remaining inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the receiver has been dropped.
+    pub fn send_all<E, S>(&mut self, event_sender: &S) -> Result<(), ReceiverDroppedError>
+    where
+        E: Event + From<Input>,
+        S: EventSender<AnyEvent> + ?Sized,
+    {
+        self.send_due::<E, S>(Duration::MAX, event_sender)
+    }
+
+    fn key(&self, keycode: KeyCode) -> Key {
+        let is_shifted = self.modifiers().contains(Modifiers::SHIFT);
+        let logical_key = match keyboard::us_characters(keycode) {
+            Some((_, shifted)) if is_shifted => LogicalKey::Character(shifted),
+            Some((character, _)) => LogicalKey::Character(character),
+            None => LogicalKey::Named(keycode),
+        };
+        Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: Some(logical_key),
+        }
+    }
+
+    /// Get the modifiers set by the held modifier keys.
+    ///
+    /// Left, and right keys set the same modifier, so it stays set until both are released.
+    fn modifiers(&self) -> Modifiers {
+        self.held_modifier_keys
+            .iter()
+            .filter_map(|keycode| Modifiers::from_keycode(*keycode))
+            .fold(Modifiers::empty(), |modifiers, modifier| {
+                modifiers | modifier
+            })
+    }
+
+    fn set_modifier(&mut self, keycode: KeyCode, is_pressed: bool) -> &mut Self {
+        if Modifiers::from_keycode(keycode).is_none() {
+            return self;
+        }
+        let old_modifiers = self.modifiers();
+        if !is_pressed {
+            self.held_modifier_keys
+                .retain(|held_keycode| *held_keycode != keycode);
+        } else if !self.held_modifier_keys.contains(&keycode) {
+            self.held_modifier_keys.push(keycode);
+        }
+        let modifiers = self.modifiers();
+        if modifiers != old_modifiers {
+            self.input(Input::ModifiersChanged { modifiers });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod input_simulator_tests {
+    use wolf_engine_events::{mpsc, EventReceiver};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestEvent(Input);
+
+    impl Event for TestEvent {}
+
+    impl From<Input> for TestEvent {
+        fn from(input: Input) -> Self {
+            Self(input)
+        }
+    }
+
+    fn key(keycode: Option<KeyCode>, character: char) -> Key {
+        Key {
+            scancode: 0,
+            keycode,
+            logical_key: Some(LogicalKey::Character(character)),
+        }
+    }
+
+    #[test]
+    fn should_type_text() {
+        let mut simulator = InputSimulator::default();
+        simulator.type_text("Hi!é");
+
+        let shift = Key {
+            scancode: 0,
+            keycode: Some(KeyCode::LeftShift),
+            logical_key: Some(LogicalKey::Named(KeyCode::LeftShift)),
+        };
+        let shifted = Modifiers::SHIFT;
+        let none = Modifiers::empty();
+        let inputs = simulator
+            .inputs()
+            .iter()
+            .map(|(_, input)| *input)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inputs,
+            vec![
+                Input::KeyPressed {
+                    key: shift,
+                    modifiers: none,
+                    is_repeat: false
+                },
+                Input::ModifiersChanged { modifiers: shifted },
+                Input::KeyPressed {
+                    key: key(Some(KeyCode::H), 'H'),
+                    modifiers: shifted,
+                    is_repeat: false
+                },
+                Input::KeyReleased {
+                    key: key(Some(KeyCode::H), 'H'),
+                    modifiers: shifted
+                },
+                Input::ModifiersChanged { modifiers: none },
+                Input::KeyReleased {
+                    key: shift,
+                    modifiers: none
+                },
+                Input::KeyPressed {
+                    key: key(Some(KeyCode::I), 'i'),
+                    modifiers: none,
+                    is_repeat: false
+                },
+                Input::KeyReleased {
+                    key: key(Some(KeyCode::I), 'i'),
+                    modifiers: none
+                },
+                Input::KeyPressed {
+                    key: shift,
+                    modifiers: none,
+                    is_repeat: false
+                },
+                Input::ModifiersChanged { modifiers: shifted },
+                Input::KeyPressed {
+                    key: key(Some(KeyCode::Num1), '!'),
+                    modifiers: shifted,
+                    is_repeat: false
+                },
+                Input::KeyReleased {
+                    key: key(Some(KeyCode::Num1), '!'),
+                    modifiers: shifted
+                },
+                Input::ModifiersChanged { modifiers: none },
+                Input::KeyReleased {
+                    key: shift,
+                    modifiers: none
+                },
+                Input::KeyPressed {
+                    key: key(None, 'é'),
+                    modifiers: none,
+                    is_repeat: false
+                },
+                Input::KeyReleased {
+                    key: key(None, 'é'),
+                    modifiers: none
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_track_overlapping_left_and_right_modifiers() {
+        let mut simulator = InputSimulator::default();
+        simulator
+            .press_key(KeyCode::LeftShift)
+            .press_key(KeyCode::RightShift)
+            .release_key(KeyCode::LeftShift)
+            .tap_key(KeyCode::A)
+            .release_key(KeyCode::RightShift);
+
+        let inputs = simulator
+            .inputs()
+            .iter()
+            .filter_map(|(_, input)| match input {
+                Input::ModifiersChanged { modifiers } => Some(("changed", *modifiers)),
+                Input::KeyPressed {
+                    key:
+                        Key {
+                            keycode: Some(KeyCode::A),
+                            ..
+                        },
+                    modifiers,
+                    ..
+                } => Some(("a", *modifiers)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inputs,
+            vec![
+                ("changed", Modifiers::SHIFT),
+                ("a", Modifiers::SHIFT),
+                ("changed", Modifiers::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_play_inputs_back_on_a_timeline() {
+        let mut simulator = InputSimulator::default();
+        simulator
+            .hold_key(KeyCode::W, Duration::from_millis(500))
+            .wait(Duration::from_millis(100))
+            .move_mouse_to(10.0, 20.0)
+            .click(MouseButton::Left);
+        let mut inputs = Vec::new();
+
+        simulator.play_due(Duration::from_millis(499), |input| inputs.push(input));
+        assert_eq!(inputs.len(), 1);
+        assert!(matches!(inputs[0], Input::KeyPressed { .. }));
+
+        simulator.play_due(Duration::from_millis(599), |input| inputs.push(input));
+        assert_eq!(inputs.len(), 2);
+        assert!(matches!(inputs[1], Input::KeyReleased { .. }));
+        assert!(!simulator.is_finished());
+
+        simulator.play_due(Duration::from_millis(600), |input| inputs.push(input));
+        assert_eq!(
+            inputs[2..],
+            [
+                Input::MouseMovedTo { x: 10.0, y: 20.0 },
+                Input::MouseButtonPressed {
+                    button: MouseButton::Left
+                },
+                Input::MouseButtonReleased {
+                    button: MouseButton::Left
+                },
+            ]
+        );
+        assert!(simulator.is_finished());
+        assert_eq!(simulator.time(), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn should_send_inputs_as_events() {
+        let (event_sender, mut event_receiver) = mpsc::event_queue::<AnyEvent>();
+        let mut simulator = InputSimulator::default();
+        simulator
+            .scroll(0.0, 1.0)
+            .wait(Duration::from_secs(1))
+            .tap_key(KeyCode::Escape);
+
+        simulator
+            .send_due::<TestEvent, _>(Duration::ZERO, &event_sender)
+            .unwrap();
+        let event = event_receiver.next_event().unwrap();
+        assert!(event_receiver.next_event().is_none());
+        assert_eq!(
+            event.downcast_ref::<TestEvent>(),
+            Some(&TestEvent(Input::MouseScrolled {
+                delta_x: 0.0,
+                delta_y: 1.0,
+                unit: ScrollUnit::Lines,
+                phase: ScrollPhase::Moved,
+            }))
+        );
+
+        simulator.send_all::<TestEvent, _>(&event_sender).unwrap();
+        let mut count = 0;
+        while event_receiver.next_event().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+}
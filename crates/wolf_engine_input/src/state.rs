@@ -0,0 +1,675 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    device::DeviceId,
+    keyboard::{Key, KeyboardLayout, Modifiers},
+    mouse::MouseButton,
+    touch::Touch,
+    Input,
+};
+
+/// Keeps track of the current input state, and fills in details which can't be known from a
+/// single input event.
+///
+/// Most [`ToInput`](crate::ToInput) adapters are stateless, so some inputs they produce are
+/// incomplete.  For example: Winit reports modifier keys separately from key presses, so the
+/// `modifiers` of a [`Input::KeyPressed`] event will always be empty.  Passing inputs through
+/// an `InputState` corrects them before they reach the game.
+///
+/// # Focus Loss
+///
+/// When a window loses focus, the key / button releases which happen afterwards are not sent to
+/// it.  To stop keys from getting "stuck," an `InputState` will release all held keys, modifiers,
+/// and mouse buttons when it receives an [`Input::FocusLost`] event.
+///
+/// An `InputState` doesn't tell input devices apart.  Use [`DeviceInputStates`] to track each
+/// device separately.
+///
+/// # Mouse Emulation
+///
+/// Games which don't handle touch input can enable mouse emulation with
+/// [`InputState::with_mouse_emulation()`].  When enabled, the primary touch (the first finger to
+/// touch the screen, while no other touch is being emulated) is also reported as the left mouse
+/// button, and the mouse position.  The original touch inputs are still passed on.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_input::{Input, InputState, keyboard::{Key, KeyCode, Modifiers}};
+/// #
+/// let mut input_state = InputState::default();
+/// let key = Key { scancode: 31, keycode: Some(KeyCode::S), logical_key: None };
+///
+/// input_state.process(Input::ModifiersChanged { modifiers: Modifiers::CONTROL }, |_| ());
+/// input_state.process(
+///     Input::KeyPressed { key, modifiers: Modifiers::empty(), is_repeat: false },
+///     |input| {
+///         assert_eq!(
+///             input,
+///             Input::KeyPressed { key, modifiers: Modifiers::CONTROL, is_repeat: false },
+///         );
+///     },
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InputState {
+    modifiers: Modifiers,
+    keyboard_layout: KeyboardLayout,
+    held_keys: Vec<Key>,
+    held_mouse_buttons: Vec<MouseButton>,
+    is_emulating_mouse: bool,
+    primary_touch: Option<u64>,
+}
+
+impl InputState {
+    /// Set whether or not the primary touch should be emulated as mouse input.
+    pub fn with_mouse_emulation(mut self, is_emulating_mouse: bool) -> Self {
+        self.is_emulating_mouse = is_emulating_mouse;
+        self
+    }
+
+    /// Get the modifier keys which are currently held.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Get the keyboard layout, as learned from key presses so far.
+    pub fn keyboard_layout(&self) -> &KeyboardLayout {
+        &self.keyboard_layout
+    }
+
+    /// Update the state from an [`Input`], then pass the completed input(s) on to the handler.
+    pub fn process<F: FnMut(Input)>(&mut self, input: Input, mut handler: F) {
+        for input in self.complete(input) {
+            self.track_held_buttons(&input);
+            handler(input);
+        }
+    }
+
+    fn complete(&mut self, input: Input) -> Vec<Input> {
+        match input {
+            Input::ModifiersChanged { modifiers } => {
+                self.modifiers = modifiers;
+                vec![input]
+            }
+            Input::KeyPressed { key, is_repeat, .. } => {
+                if self.modifiers.is_empty() {
+                    self.keyboard_layout.learn(&key);
+                }
+                vec![Input::KeyPressed {
+                    key,
+                    modifiers: self.modifiers,
+                    is_repeat,
+                }]
+            }
+            Input::KeyReleased { key, .. } => vec![Input::KeyReleased {
+                key,
+                modifiers: self.modifiers,
+            }],
+            Input::FocusLost => self.release_all(input),
+            Input::TouchStarted { touch } => {
+                if self.is_emulating_mouse && self.primary_touch.is_none() {
+                    self.primary_touch = Some(touch.finger_id);
+                    vec![
+                        input,
+                        Self::emulated_mouse_move(&touch),
+                        Input::MouseButtonPressed {
+                            button: MouseButton::Left,
+                        },
+                    ]
+                } else {
+                    vec![input]
+                }
+            }
+            Input::TouchMoved { touch } => {
+                if self.is_primary_touch(&touch) {
+                    vec![input, Self::emulated_mouse_move(&touch)]
+                } else {
+                    vec![input]
+                }
+            }
+            Input::TouchEnded { touch } | Input::TouchCancelled { touch } => {
+                if self.is_primary_touch(&touch) {
+                    self.primary_touch = None;
+                    vec![
+                        input,
+                        Self::emulated_mouse_move(&touch),
+                        Input::MouseButtonReleased {
+                            button: MouseButton::Left,
+                        },
+                    ]
+                } else {
+                    vec![input]
+                }
+            }
+            _ => vec![input],
+        }
+    }
+
+    /// Synthesize releases for everything which is still held, so the state doesn't get stuck
+    /// when the release events are sent somewhere else, such as another window.
+    fn release_all(&mut self, input: Input) -> Vec<Input> {
+        let mut inputs = Vec::new();
+        if !self.modifiers.is_empty() {
+            self.modifiers = Modifiers::empty();
+            inputs.push(Input::ModifiersChanged {
+                modifiers: Modifiers::empty(),
+            });
+        }
+        inputs.extend(self.held_keys.iter().map(|key| Input::KeyReleased {
+            key: *key,
+            modifiers: Modifiers::empty(),
+        }));
+        inputs.extend(
+            self.held_mouse_buttons
+                .iter()
+                .map(|button| Input::MouseButtonReleased { button: *button }),
+        );
+        self.primary_touch = None;
+        inputs.push(input);
+        inputs
+    }
+
+    fn track_held_buttons(&mut self, input: &Input) {
+        match input {
+            Input::KeyPressed { key, .. } if !self.is_key_held(key) => self.held_keys.push(*key),
+            Input::KeyReleased { key, .. } => {
+                self.held_keys.retain(|held| !Self::is_same_key(held, key))
+            }
+            Input::MouseButtonPressed { button } if !self.held_mouse_buttons.contains(button) => {
+                self.held_mouse_buttons.push(*button)
+            }
+            Input::MouseButtonReleased { button } => {
+                self.held_mouse_buttons.retain(|held| held != button)
+            }
+            _ => (),
+        }
+    }
+
+    fn is_key_held(&self, key: &Key) -> bool {
+        self.held_keys
+            .iter()
+            .any(|held| Self::is_same_key(held, key))
+    }
+
+    /// Check if two keys are the same physical key.
+    ///
+    /// The logical key is ignored, because it may change while the key is held.
+    fn is_same_key(a: &Key, b: &Key) -> bool {
+        a.scancode == b.scancode && a.keycode == b.keycode
+    }
+
+    fn is_primary_touch(&self, touch: &Touch) -> bool {
+        self.is_emulating_mouse && self.primary_touch == Some(touch.finger_id)
+    }
+
+    fn emulated_mouse_move(touch: &Touch) -> Input {
+        Input::MouseMovedTo {
+            x: touch.x,
+            y: touch.y,
+        }
+    }
+}
+
+/// Keeps a separate [`InputState`] for each input device.
+///
+/// Each device's inputs only update that device's state, so keys held on one keyboard aren't
+/// released by another.  Inputs without a device share their own state.
+///
+/// Some inputs aren't tied to a device, and affect all of them:
+///
+/// - [`Input::ModifiersChanged`] without a device updates every device's modifiers, since some
+///   platforms, such as Winit, only report modifiers for the whole window.
+/// - [`Input::FocusLost`] releases everything held on every device.  Each synthesized release
+///   is passed on with the id of the device which pressed it.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_input::{device::DeviceId, DeviceInputStates, Input, mouse::MouseButton};
+/// #
+/// let mut input_states = DeviceInputStates::default();
+/// let mouse = DeviceId(1);
+/// input_states.process(
+///     Some(mouse),
+///     Input::MouseButtonPressed { button: MouseButton::Left },
+///     |_, _| (),
+/// );
+///
+/// let mut inputs = Vec::new();
+/// input_states.process(None, Input::FocusLost, |device_id, input| {
+///     inputs.push((device_id, input))
+/// });
+///
+/// assert_eq!(
+///     inputs,
+///     vec![
+///         (Some(mouse), Input::MouseButtonReleased { button: MouseButton::Left }),
+///         (None, Input::FocusLost),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DeviceInputStates {
+    template: InputState,
+    input_states: BTreeMap<Option<DeviceId>, InputState>,
+}
+
+impl DeviceInputStates {
+    /// Set whether or not the primary touch should be emulated as mouse input.
+    ///
+    /// See [`InputState::with_mouse_emulation()`] for details.
+    pub fn with_mouse_emulation(mut self, is_emulating_mouse: bool) -> Self {
+        self.template = self.template.with_mouse_emulation(is_emulating_mouse);
+        for input_state in self.input_states.values_mut() {
+            *input_state = input_state.clone().with_mouse_emulation(is_emulating_mouse);
+        }
+        self
+    }
+
+    /// Get a device's [`InputState`], if it has sent any inputs.
+    pub fn input_state(&self, device_id: Option<DeviceId>) -> Option<&InputState> {
+        self.input_states.get(&device_id)
+    }
+
+    /// Update the device's state from an [`Input`], then pass the completed input(s) on to the
+    /// handler, along with the device they belong to.
+    pub fn process<F: FnMut(Option<DeviceId>, Input)>(
+        &mut self,
+        device_id: Option<DeviceId>,
+        input: Input,
+        mut handler: F,
+    ) {
+        if device_id.is_some() {
+            self.input_state_mut(device_id)
+                .process(input, |input| handler(device_id, input));
+            return;
+        }
+        self.input_state_mut(None);
+        match input {
+            Input::ModifiersChanged { .. } => {
+                for (state_device_id, input_state) in self.input_states.iter_mut() {
+                    input_state.process(input, |input| {
+                        if state_device_id.is_none() {
+                            handler(None, input)
+                        }
+                    });
+                }
+            }
+            Input::FocusLost => {
+                // Modifiers are shared, so only the device-less state reports them changing.
+                for (state_device_id, input_state) in self.input_states.iter_mut() {
+                    input_state.process(input, |input| match input {
+                        Input::FocusLost => (),
+                        Input::ModifiersChanged { .. } if state_device_id.is_some() => (),
+                        input => handler(*state_device_id, input),
+                    });
+                }
+                handler(None, Input::FocusLost);
+            }
+            input => self
+                .input_state_mut(None)
+                .process(input, |input| handler(None, input)),
+        }
+    }
+
+    fn input_state_mut(&mut self, device_id: Option<DeviceId>) -> &mut InputState {
+        self.input_states
+            .entry(device_id)
+            .or_insert_with(|| self.template.clone())
+    }
+}
+
+#[cfg(test)]
+mod input_state_tests {
+    use crate::keyboard::{KeyCode, LogicalKey};
+
+    use super::*;
+
+    fn process(input_state: &mut InputState, input: Input) -> Vec<Input> {
+        let mut inputs = Vec::new();
+        input_state.process(input, |input| inputs.push(input));
+        inputs
+    }
+
+    #[test]
+    fn should_attach_modifiers_to_key_events() {
+        let mut input_state = InputState::default();
+        let key = Key {
+            scancode: 31,
+            keycode: Some(KeyCode::S),
+            logical_key: Some(LogicalKey::Character('s')),
+        };
+
+        process(
+            &mut input_state,
+            Input::ModifiersChanged {
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+            },
+        );
+        assert_eq!(
+            input_state.modifiers(),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        );
+
+        let pressed = process(
+            &mut input_state,
+            Input::KeyPressed {
+                key,
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            },
+        );
+        assert_eq!(
+            pressed,
+            vec![Input::KeyPressed {
+                key,
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                is_repeat: false,
+            }]
+        );
+
+        process(
+            &mut input_state,
+            Input::ModifiersChanged {
+                modifiers: Modifiers::empty(),
+            },
+        );
+        let released = process(
+            &mut input_state,
+            Input::KeyReleased {
+                key,
+                modifiers: Modifiers::CONTROL,
+            },
+        );
+        assert_eq!(
+            released,
+            vec![Input::KeyReleased {
+                key,
+                modifiers: Modifiers::empty(),
+            }]
+        );
+    }
+
+    #[test]
+    fn should_learn_keyboard_layout_from_unmodified_key_presses() {
+        let mut input_state = InputState::default();
+        let key = |character| Key {
+            scancode: 17,
+            keycode: Some(KeyCode::W),
+            logical_key: Some(LogicalKey::Character(character)),
+        };
+
+        process(
+            &mut input_state,
+            Input::KeyPressed {
+                key: key('z'),
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            },
+        );
+        process(
+            &mut input_state,
+            Input::ModifiersChanged {
+                modifiers: Modifiers::SHIFT,
+            },
+        );
+        process(
+            &mut input_state,
+            Input::KeyPressed {
+                key: key('Z'),
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            },
+        );
+
+        assert_eq!(
+            input_state.keyboard_layout().logical_key(KeyCode::W),
+            Some(LogicalKey::Character('z'))
+        );
+    }
+
+    #[test]
+    fn should_release_held_inputs_on_focus_loss() {
+        let mut input_state = InputState::default();
+        let key = |scancode, logical_key| Key {
+            scancode,
+            keycode: None,
+            logical_key,
+        };
+
+        for input in [
+            Input::ModifiersChanged {
+                modifiers: Modifiers::SHIFT,
+            },
+            Input::KeyPressed {
+                key: key(1, Some(LogicalKey::Character('a'))),
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            },
+            Input::KeyPressed {
+                key: key(2, None),
+                modifiers: Modifiers::empty(),
+                is_repeat: false,
+            },
+            Input::KeyReleased {
+                key: key(2, None),
+                modifiers: Modifiers::empty(),
+            },
+            Input::MouseButtonPressed {
+                button: MouseButton::Right,
+            },
+        ] {
+            process(&mut input_state, input);
+        }
+
+        assert_eq!(
+            process(&mut input_state, Input::FocusLost),
+            vec![
+                Input::ModifiersChanged {
+                    modifiers: Modifiers::empty()
+                },
+                Input::KeyReleased {
+                    key: key(1, Some(LogicalKey::Character('a'))),
+                    modifiers: Modifiers::empty(),
+                },
+                Input::MouseButtonReleased {
+                    button: MouseButton::Right
+                },
+                Input::FocusLost,
+            ]
+        );
+        assert_eq!(
+            process(&mut input_state, Input::FocusLost),
+            vec![Input::FocusLost],
+            "Nothing should be held after a focus loss"
+        );
+    }
+
+    #[test]
+    fn should_pass_through_other_inputs() {
+        let mut input_state = InputState::default();
+        let input = Input::MouseMovedTo { x: 1.0, y: 2.0 };
+
+        assert_eq!(process(&mut input_state, input), vec![input]);
+    }
+
+    fn touch(finger_id: u64, x: f32, y: f32) -> Touch {
+        Touch {
+            finger_id,
+            x,
+            y,
+            pressure: None,
+        }
+    }
+
+    #[test]
+    fn should_emulate_mouse_with_primary_touch() {
+        let mut input_state = InputState::default().with_mouse_emulation(true);
+        let primary = touch(1, 10.0, 20.0);
+        let secondary = touch(2, 30.0, 40.0);
+
+        assert_eq!(
+            process(&mut input_state, Input::TouchStarted { touch: primary }),
+            vec![
+                Input::TouchStarted { touch: primary },
+                Input::MouseMovedTo { x: 10.0, y: 20.0 },
+                Input::MouseButtonPressed {
+                    button: MouseButton::Left
+                },
+            ]
+        );
+        assert_eq!(
+            process(&mut input_state, Input::TouchStarted { touch: secondary }),
+            vec![Input::TouchStarted { touch: secondary }],
+            "Only the primary touch should be emulated"
+        );
+
+        let moved = touch(1, 15.0, 25.0);
+        assert_eq!(
+            process(&mut input_state, Input::TouchMoved { touch: moved }),
+            vec![
+                Input::TouchMoved { touch: moved },
+                Input::MouseMovedTo { x: 15.0, y: 25.0 },
+            ]
+        );
+
+        assert_eq!(
+            process(&mut input_state, Input::TouchEnded { touch: moved }),
+            vec![
+                Input::TouchEnded { touch: moved },
+                Input::MouseMovedTo { x: 15.0, y: 25.0 },
+                Input::MouseButtonReleased {
+                    button: MouseButton::Left
+                },
+            ]
+        );
+        assert_eq!(
+            process(&mut input_state, Input::TouchEnded { touch: secondary }),
+            vec![Input::TouchEnded { touch: secondary }],
+            "The secondary touch should not become the primary touch"
+        );
+    }
+
+    #[test]
+    fn should_not_emulate_mouse_by_default() {
+        let mut input_state = InputState::default();
+        let input = Input::TouchStarted {
+            touch: touch(1, 10.0, 20.0),
+        };
+
+        assert_eq!(process(&mut input_state, input), vec![input]);
+    }
+}
+
+#[cfg(test)]
+mod device_input_states_tests {
+    use crate::keyboard::{KeyCode, LogicalKey};
+
+    use super::*;
+
+    fn key(keycode: KeyCode) -> Key {
+        Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: Some(LogicalKey::Named(keycode)),
+        }
+    }
+
+    fn press(keycode: KeyCode) -> Input {
+        Input::KeyPressed {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+            is_repeat: false,
+        }
+    }
+
+    fn process(
+        input_states: &mut DeviceInputStates,
+        device_id: Option<DeviceId>,
+        input: Input,
+    ) -> Vec<(Option<DeviceId>, Input)> {
+        let mut inputs = Vec::new();
+        input_states.process(device_id, input, |device_id, input| {
+            inputs.push((device_id, input))
+        });
+        inputs
+    }
+
+    #[test]
+    fn should_track_held_keys_separately_for_each_device() {
+        let mut input_states = DeviceInputStates::default();
+        let keyboard_a = Some(DeviceId(1));
+        let keyboard_b = Some(DeviceId(2));
+        process(&mut input_states, keyboard_a, press(KeyCode::A));
+        process(&mut input_states, keyboard_b, press(KeyCode::A));
+
+        process(
+            &mut input_states,
+            keyboard_b,
+            Input::KeyReleased {
+                key: key(KeyCode::A),
+                modifiers: Modifiers::empty(),
+            },
+        );
+
+        assert_eq!(
+            process(&mut input_states, None, Input::FocusLost),
+            vec![
+                (
+                    keyboard_a,
+                    Input::KeyReleased {
+                        key: key(KeyCode::A),
+                        modifiers: Modifiers::empty(),
+                    }
+                ),
+                (None, Input::FocusLost),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_share_device_less_modifiers_with_every_device() {
+        let mut input_states = DeviceInputStates::default();
+        let keyboard = Some(DeviceId(1));
+        process(&mut input_states, keyboard, press(KeyCode::LeftShift));
+
+        let modifiers_changed = Input::ModifiersChanged {
+            modifiers: Modifiers::SHIFT,
+        };
+        assert_eq!(
+            process(&mut input_states, None, modifiers_changed),
+            vec![(None, modifiers_changed)]
+        );
+        assert_eq!(
+            process(&mut input_states, keyboard, press(KeyCode::A)),
+            vec![(
+                keyboard,
+                Input::KeyPressed {
+                    key: key(KeyCode::A),
+                    modifiers: Modifiers::SHIFT,
+                    is_repeat: false,
+                }
+            )]
+        );
+
+        let released = process(&mut input_states, None, Input::FocusLost);
+        assert_eq!(
+            released[0],
+            (
+                None,
+                Input::ModifiersChanged {
+                    modifiers: Modifiers::empty()
+                }
+            )
+        );
+        assert_eq!(
+            released
+                .iter()
+                .filter(|(_, input)| matches!(input, Input::ModifiersChanged { .. }))
+                .count(),
+            1,
+            "Shared modifiers should only be released once"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Provides types related to touch input.
+
+/// Represents a finger touching the screen.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Touch {
+    /// Identifies the finger.
+    ///
+    /// The id is unique among fingers currently touching the screen, and stays the same for the
+    /// duration of the touch.  It may be re-used for later touches.
+    pub finger_id: u64,
+    pub x: f32,
+    pub y: f32,
+    /// How hard the screen is being pressed, from `0.0` to `1.0`, if the platform supports it.
+    pub pressure: Option<f32>,
+}
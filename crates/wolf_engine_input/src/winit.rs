@@ -1,11 +1,15 @@
-use crate::keyboard::{Key, KeyCode};
-use crate::mouse::MouseButton;
+use crate::keyboard::{Key, KeyCode, LogicalKey, Modifiers};
+use crate::mouse::{MouseButton, ScrollPhase, ScrollUnit};
+use crate::touch::Touch;
 use crate::{Input, ToInput};
 
-use winit::event::{KeyEvent, MouseScrollDelta, WindowEvent};
+use winit::event::{KeyEvent, MouseScrollDelta, Touch as WinitTouch, TouchPhase, WindowEvent};
 use winit::{
     event::{DeviceEvent, ElementState, Event, MouseButton as WinitMouseButton, RawKeyEvent},
-    keyboard::{KeyCode as WinitKeyCode, PhysicalKey},
+    keyboard::{
+        Key as WinitKey, KeyCode as WinitKeyCode, ModifiersState, NamedKey, NativeKeyCode,
+        PhysicalKey,
+    },
     platform::scancode::PhysicalKeyExtScancode,
 };
 
@@ -23,6 +27,13 @@ impl ToInput for WindowEvent {
     fn to_input(&self) -> Option<Input> {
         match self {
             WindowEvent::KeyboardInput { event, .. } => Some(event.clone().into()),
+            WindowEvent::ModifiersChanged(modifiers) => Some(Input::ModifiersChanged {
+                modifiers: modifiers.state().into(),
+            }),
+            WindowEvent::CursorEntered { .. } => Some(Input::CursorEntered),
+            WindowEvent::CursorLeft { .. } => Some(Input::CursorLeft),
+            WindowEvent::Focused(true) => Some(Input::FocusGained),
+            WindowEvent::Focused(false) => Some(Input::FocusLost),
             WindowEvent::CursorMoved { position, .. } => Some(Input::MouseMovedTo {
                 x: position.x.trunc() as f32,
                 y: position.y.trunc() as f32,
@@ -35,38 +46,188 @@ impl ToInput for WindowEvent {
                     button: (*button).into(),
                 }),
             },
-            WindowEvent::MouseWheel {
-                delta: MouseScrollDelta::LineDelta(x, y),
-                ..
-            } => Some(Input::MouseScrolled {
-                delta_x: *x,
-                delta_y: *y,
-            }),
+            WindowEvent::MouseWheel { delta, phase, .. } => {
+                let ((delta_x, delta_y), unit) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => ((*x, *y), ScrollUnit::Lines),
+                    MouseScrollDelta::PixelDelta(position) => {
+                        ((position.x as f32, position.y as f32), ScrollUnit::Pixels)
+                    }
+                };
+                Some(Input::MouseScrolled {
+                    delta_x,
+                    delta_y,
+                    unit,
+                    phase: (*phase).into(),
+                })
+            }
+            WindowEvent::Touch(touch) => Some((*touch).into()),
             _ => None,
         }
     }
 }
 
+impl From<WinitTouch> for Input {
+    fn from(event: WinitTouch) -> Input {
+        let touch = Touch {
+            finger_id: event.id,
+            x: event.location.x as f32,
+            y: event.location.y as f32,
+            pressure: event.force.map(|force| force.normalized() as f32),
+        };
+
+        match event.phase {
+            TouchPhase::Started => Input::TouchStarted { touch },
+            TouchPhase::Moved => Input::TouchMoved { touch },
+            TouchPhase::Ended => Input::TouchEnded { touch },
+            TouchPhase::Cancelled => Input::TouchCancelled { touch },
+        }
+    }
+}
+
+/// Winit reports modifiers separately from key events, so the converted input's `modifiers` are
+/// always empty.  Pass it through an [`InputState`](crate::InputState) to fill them in.
 impl From<KeyEvent> for Input {
     fn from(event: KeyEvent) -> Input {
+        let keycode = match event.physical_key.into() {
+            KeyCode::Unknown => None,
+            keycode => Some(keycode),
+        };
         let key = Key {
             scancode: event.physical_key.to_scancode().unwrap_or(0),
-            keycode: match event.physical_key.into() {
-                KeyCode::Unknown => None,
-                keycode => Some(keycode),
-            },
+            keycode,
+            logical_key: logical_key(&event.logical_key, keycode),
         };
 
         match event.state {
             ElementState::Pressed => Input::KeyPressed {
                 key,
+                modifiers: Modifiers::empty(),
                 is_repeat: event.repeat,
             },
-            ElementState::Released => Input::KeyReleased { key },
+            ElementState::Released => Input::KeyReleased {
+                key,
+                modifiers: Modifiers::empty(),
+            },
+        }
+    }
+}
+
+/// Get the [`LogicalKey`] for a Winit logical key.
+///
+/// Winit doesn't tell left / right modifier keys apart in logical keys, so the physical keycode
+/// is used for those instead.
+fn logical_key(logical_key: &WinitKey, keycode: Option<KeyCode>) -> Option<LogicalKey> {
+    match logical_key {
+        WinitKey::Character(characters) => characters.chars().next().map(LogicalKey::Character),
+        WinitKey::Dead(character) => character.map(LogicalKey::Character),
+        WinitKey::Named(NamedKey::Shift | NamedKey::Control | NamedKey::Alt | NamedKey::Super) => {
+            keycode.map(LogicalKey::Named)
         }
+        WinitKey::Named(named_key) => named_keycode(*named_key).map(LogicalKey::Named),
+        WinitKey::Unidentified(_) => None,
     }
 }
 
+fn named_keycode(named_key: NamedKey) -> Option<KeyCode> {
+    let keycode = match named_key {
+        NamedKey::Escape => KeyCode::Escape,
+        NamedKey::F1 => KeyCode::F1,
+        NamedKey::F2 => KeyCode::F2,
+        NamedKey::F3 => KeyCode::F3,
+        NamedKey::F4 => KeyCode::F4,
+        NamedKey::F5 => KeyCode::F5,
+        NamedKey::F6 => KeyCode::F6,
+        NamedKey::F7 => KeyCode::F7,
+        NamedKey::F8 => KeyCode::F8,
+        NamedKey::F9 => KeyCode::F9,
+        NamedKey::F10 => KeyCode::F10,
+        NamedKey::F11 => KeyCode::F11,
+        NamedKey::F12 => KeyCode::F12,
+        NamedKey::PrintScreen => KeyCode::PrintScreen,
+        NamedKey::ScrollLock => KeyCode::ScrollLock,
+        NamedKey::Pause => KeyCode::Pause,
+        NamedKey::Backspace => KeyCode::Backspace,
+        NamedKey::Insert => KeyCode::Insert,
+        NamedKey::Home => KeyCode::Home,
+        NamedKey::PageUp => KeyCode::PageUp,
+        NamedKey::NumLock => KeyCode::NumLock,
+        NamedKey::Tab => KeyCode::Tab,
+        NamedKey::Delete => KeyCode::Delete,
+        NamedKey::End => KeyCode::End,
+        NamedKey::PageDown => KeyCode::PageDown,
+        NamedKey::CapsLock => KeyCode::CapsLock,
+        NamedKey::Enter => KeyCode::Enter,
+        NamedKey::ArrowUp => KeyCode::UpArrow,
+        NamedKey::Space => KeyCode::Space,
+        NamedKey::ArrowLeft => KeyCode::LeftArrow,
+        NamedKey::ArrowDown => KeyCode::DownArrow,
+        NamedKey::ArrowRight => KeyCode::RightArrow,
+        NamedKey::F13 => KeyCode::F13,
+        NamedKey::F14 => KeyCode::F14,
+        NamedKey::F15 => KeyCode::F15,
+        NamedKey::F16 => KeyCode::F16,
+        NamedKey::F17 => KeyCode::F17,
+        NamedKey::F18 => KeyCode::F18,
+        NamedKey::F19 => KeyCode::F19,
+        NamedKey::F20 => KeyCode::F20,
+        NamedKey::F21 => KeyCode::F21,
+        NamedKey::F22 => KeyCode::F22,
+        NamedKey::F23 => KeyCode::F23,
+        NamedKey::F24 => KeyCode::F24,
+        NamedKey::F25 => KeyCode::F25,
+        NamedKey::F26 => KeyCode::F26,
+        NamedKey::F27 => KeyCode::F27,
+        NamedKey::F28 => KeyCode::F28,
+        NamedKey::F29 => KeyCode::F29,
+        NamedKey::F30 => KeyCode::F30,
+        NamedKey::F31 => KeyCode::F31,
+        NamedKey::F32 => KeyCode::F32,
+        NamedKey::F33 => KeyCode::F33,
+        NamedKey::F34 => KeyCode::F34,
+        NamedKey::F35 => KeyCode::F35,
+        NamedKey::Convert => KeyCode::Convert,
+        NamedKey::NonConvert => KeyCode::NonConvert,
+        NamedKey::KanaMode => KeyCode::KanaMode,
+        NamedKey::Hiragana => KeyCode::Hiragana,
+        NamedKey::Katakana => KeyCode::Katakana,
+        NamedKey::ContextMenu => KeyCode::ContextMenu,
+        NamedKey::Fn => KeyCode::Fn,
+        NamedKey::FnLock => KeyCode::FnLock,
+        NamedKey::Help => KeyCode::Help,
+        NamedKey::Meta => KeyCode::Meta,
+        NamedKey::Hyper => KeyCode::Hyper,
+        NamedKey::Again => KeyCode::Again,
+        NamedKey::Copy => KeyCode::Copy,
+        NamedKey::Cut => KeyCode::Cut,
+        NamedKey::Paste => KeyCode::Paste,
+        NamedKey::Find => KeyCode::Find,
+        NamedKey::Open => KeyCode::Open,
+        NamedKey::Props => KeyCode::Props,
+        NamedKey::Select => KeyCode::Select,
+        NamedKey::Undo => KeyCode::Undo,
+        NamedKey::MediaPlayPause => KeyCode::MediaPlayPause,
+        NamedKey::MediaStop => KeyCode::MediaStop,
+        NamedKey::MediaTrackNext => KeyCode::MediaTrackNext,
+        NamedKey::MediaTrackPrevious => KeyCode::MediaTrackPrevious,
+        NamedKey::AudioVolumeDown => KeyCode::VolumeDown,
+        NamedKey::AudioVolumeMute => KeyCode::VolumeMute,
+        NamedKey::AudioVolumeUp => KeyCode::VolumeUp,
+        NamedKey::BrowserBack => KeyCode::BrowserBack,
+        NamedKey::BrowserForward => KeyCode::BrowserForward,
+        NamedKey::BrowserRefresh => KeyCode::BrowserRefresh,
+        NamedKey::BrowserStop => KeyCode::BrowserStop,
+        NamedKey::BrowserSearch => KeyCode::BrowserSearch,
+        NamedKey::BrowserFavorites => KeyCode::BrowserFavorites,
+        NamedKey::BrowserHome => KeyCode::BrowserHome,
+        NamedKey::LaunchMail => KeyCode::LaunchMail,
+        NamedKey::Eject => KeyCode::Eject,
+        NamedKey::Power => KeyCode::Power,
+        NamedKey::WakeUp => KeyCode::WakeUp,
+        _ => return None,
+    };
+    Some(keycode)
+}
+
 impl ToInput for DeviceEvent {
     fn to_input(&self) -> Option<Input> {
         match self {
@@ -80,6 +241,8 @@ impl ToInput for DeviceEvent {
     }
 }
 
+/// Raw key events don't include modifiers, so the converted input's `modifiers` are always empty.
+/// Pass it through an [`InputState`](crate::InputState) to fill them in.
 impl From<RawKeyEvent> for Input {
     fn from(event: RawKeyEvent) -> Input {
         let key = Key {
@@ -88,13 +251,40 @@ impl From<RawKeyEvent> for Input {
                 KeyCode::Unknown => None,
                 keycode => Some(keycode),
             },
+            logical_key: None,
         };
         match event.state {
             ElementState::Pressed => Input::KeyPressed {
                 key,
+                modifiers: Modifiers::empty(),
                 is_repeat: false,
             },
-            ElementState::Released => Input::KeyReleased { key },
+            ElementState::Released => Input::KeyReleased {
+                key,
+                modifiers: Modifiers::empty(),
+            },
+        }
+    }
+}
+
+impl From<ModifiersState> for Modifiers {
+    fn from(state: ModifiersState) -> Self {
+        let mut modifiers = Modifiers::empty();
+        modifiers.set(Modifiers::SHIFT, state.shift_key());
+        modifiers.set(Modifiers::CONTROL, state.control_key());
+        modifiers.set(Modifiers::ALT, state.alt_key());
+        modifiers.set(Modifiers::SUPER, state.super_key());
+        modifiers
+    }
+}
+
+impl From<TouchPhase> for ScrollPhase {
+    fn from(phase: TouchPhase) -> Self {
+        match phase {
+            TouchPhase::Started => ScrollPhase::Started,
+            TouchPhase::Moved => ScrollPhase::Moved,
+            TouchPhase::Ended => ScrollPhase::Ended,
+            TouchPhase::Cancelled => ScrollPhase::Cancelled,
         }
     }
 }
@@ -121,6 +311,15 @@ impl From<PhysicalKey> for KeyCode {
     }
 }
 
+impl From<KeyCode> for PhysicalKey {
+    fn from(keycode: KeyCode) -> PhysicalKey {
+        match WinitKeyCode::try_from(keycode) {
+            Ok(winit_keycode) => PhysicalKey::Code(winit_keycode),
+            Err(_) => PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+        }
+    }
+}
+
 impl From<WinitKeyCode> for KeyCode {
     fn from(keycode: WinitKeyCode) -> Self {
         match keycode {
@@ -162,7 +361,7 @@ impl From<WinitKeyCode> for KeyCode {
             WinitKeyCode::NumLock => KeyCode::NumLock,
             WinitKeyCode::NumpadDivide => KeyCode::NumpadDivide,
             WinitKeyCode::NumpadMultiply => KeyCode::NumpadMultiply,
-            WinitKeyCode::NumpadSubtract => KeyCode::Backspace,
+            WinitKeyCode::NumpadSubtract => KeyCode::NumpadSubtract,
 
             WinitKeyCode::Tab => KeyCode::Tab,
             WinitKeyCode::KeyQ => KeyCode::Q,
@@ -183,6 +382,7 @@ impl From<WinitKeyCode> for KeyCode {
             WinitKeyCode::Numpad7 => KeyCode::Numpad7,
             WinitKeyCode::Numpad8 => KeyCode::Numpad8,
             WinitKeyCode::Numpad9 => KeyCode::Numpad9,
+            WinitKeyCode::NumpadAdd => KeyCode::NumpadAdd,
 
             WinitKeyCode::CapsLock => KeyCode::CapsLock,
             WinitKeyCode::KeyA => KeyCode::A,
@@ -201,7 +401,7 @@ impl From<WinitKeyCode> for KeyCode {
             WinitKeyCode::Numpad5 => KeyCode::Numpad5,
             WinitKeyCode::Numpad6 => KeyCode::Numpad6,
 
-            WinitKeyCode::ShiftLeft => KeyCode::RightShift,
+            WinitKeyCode::ShiftLeft => KeyCode::LeftShift,
             WinitKeyCode::KeyZ => KeyCode::Z,
             WinitKeyCode::KeyX => KeyCode::X,
             WinitKeyCode::KeyC => KeyCode::C,
@@ -226,13 +426,557 @@ impl From<WinitKeyCode> for KeyCode {
             WinitKeyCode::AltRight => KeyCode::RightAlt,
             WinitKeyCode::SuperRight => KeyCode::RightSuper,
             WinitKeyCode::ControlRight => KeyCode::RightControl,
-            WinitKeyCode::ArrowLeft => KeyCode::RightArrow,
+            WinitKeyCode::ArrowLeft => KeyCode::LeftArrow,
             WinitKeyCode::ArrowDown => KeyCode::DownArrow,
             WinitKeyCode::ArrowRight => KeyCode::RightArrow,
             WinitKeyCode::Numpad0 => KeyCode::Numpad0,
             WinitKeyCode::NumpadDecimal => KeyCode::NumpadDecimal,
 
+            WinitKeyCode::F13 => KeyCode::F13,
+            WinitKeyCode::F14 => KeyCode::F14,
+            WinitKeyCode::F15 => KeyCode::F15,
+            WinitKeyCode::F16 => KeyCode::F16,
+            WinitKeyCode::F17 => KeyCode::F17,
+            WinitKeyCode::F18 => KeyCode::F18,
+            WinitKeyCode::F19 => KeyCode::F19,
+            WinitKeyCode::F20 => KeyCode::F20,
+            WinitKeyCode::F21 => KeyCode::F21,
+            WinitKeyCode::F22 => KeyCode::F22,
+            WinitKeyCode::F23 => KeyCode::F23,
+            WinitKeyCode::F24 => KeyCode::F24,
+            WinitKeyCode::F25 => KeyCode::F25,
+            WinitKeyCode::F26 => KeyCode::F26,
+            WinitKeyCode::F27 => KeyCode::F27,
+            WinitKeyCode::F28 => KeyCode::F28,
+            WinitKeyCode::F29 => KeyCode::F29,
+            WinitKeyCode::F30 => KeyCode::F30,
+            WinitKeyCode::F31 => KeyCode::F31,
+            WinitKeyCode::F32 => KeyCode::F32,
+            WinitKeyCode::F33 => KeyCode::F33,
+            WinitKeyCode::F34 => KeyCode::F34,
+            WinitKeyCode::F35 => KeyCode::F35,
+
+            WinitKeyCode::NumpadBackspace => KeyCode::NumpadBackspace,
+            WinitKeyCode::NumpadClear => KeyCode::NumpadClear,
+            WinitKeyCode::NumpadClearEntry => KeyCode::NumpadClearEntry,
+            WinitKeyCode::NumpadComma => KeyCode::NumpadComma,
+            WinitKeyCode::NumpadEqual => KeyCode::NumpadEquals,
+            WinitKeyCode::NumpadHash => KeyCode::NumpadHash,
+            WinitKeyCode::NumpadStar => KeyCode::NumpadStar,
+            WinitKeyCode::NumpadParenLeft => KeyCode::NumpadLeftParen,
+            WinitKeyCode::NumpadParenRight => KeyCode::NumpadRightParen,
+            WinitKeyCode::NumpadMemoryAdd => KeyCode::NumpadMemoryAdd,
+            WinitKeyCode::NumpadMemoryClear => KeyCode::NumpadMemoryClear,
+            WinitKeyCode::NumpadMemoryRecall => KeyCode::NumpadMemoryRecall,
+            WinitKeyCode::NumpadMemoryStore => KeyCode::NumpadMemoryStore,
+            WinitKeyCode::NumpadMemorySubtract => KeyCode::NumpadMemorySubtract,
+
+            WinitKeyCode::IntlBackslash => KeyCode::IntlBackslash,
+            WinitKeyCode::IntlRo => KeyCode::IntlRo,
+            WinitKeyCode::IntlYen => KeyCode::IntlYen,
+            WinitKeyCode::Convert => KeyCode::Convert,
+            WinitKeyCode::NonConvert => KeyCode::NonConvert,
+            WinitKeyCode::KanaMode => KeyCode::KanaMode,
+            WinitKeyCode::Hiragana => KeyCode::Hiragana,
+            WinitKeyCode::Katakana => KeyCode::Katakana,
+            WinitKeyCode::Lang1 => KeyCode::Lang1,
+            WinitKeyCode::Lang2 => KeyCode::Lang2,
+            WinitKeyCode::Lang3 => KeyCode::Lang3,
+            WinitKeyCode::Lang4 => KeyCode::Lang4,
+            WinitKeyCode::Lang5 => KeyCode::Lang5,
+
+            WinitKeyCode::ContextMenu => KeyCode::ContextMenu,
+            WinitKeyCode::Fn => KeyCode::Fn,
+            WinitKeyCode::FnLock => KeyCode::FnLock,
+            WinitKeyCode::Help => KeyCode::Help,
+            WinitKeyCode::Meta => KeyCode::Meta,
+            WinitKeyCode::Hyper => KeyCode::Hyper,
+            WinitKeyCode::Turbo => KeyCode::Turbo,
+            WinitKeyCode::Abort => KeyCode::Abort,
+            WinitKeyCode::Resume => KeyCode::Resume,
+            WinitKeyCode::Suspend => KeyCode::Suspend,
+            WinitKeyCode::Again => KeyCode::Again,
+            WinitKeyCode::Copy => KeyCode::Copy,
+            WinitKeyCode::Cut => KeyCode::Cut,
+            WinitKeyCode::Paste => KeyCode::Paste,
+            WinitKeyCode::Find => KeyCode::Find,
+            WinitKeyCode::Open => KeyCode::Open,
+            WinitKeyCode::Props => KeyCode::Props,
+            WinitKeyCode::Select => KeyCode::Select,
+            WinitKeyCode::Undo => KeyCode::Undo,
+
+            WinitKeyCode::MediaPlayPause => KeyCode::MediaPlayPause,
+            WinitKeyCode::MediaStop => KeyCode::MediaStop,
+            WinitKeyCode::MediaTrackNext => KeyCode::MediaTrackNext,
+            WinitKeyCode::MediaTrackPrevious => KeyCode::MediaTrackPrevious,
+            WinitKeyCode::MediaSelect => KeyCode::MediaSelect,
+            WinitKeyCode::AudioVolumeDown => KeyCode::VolumeDown,
+            WinitKeyCode::AudioVolumeMute => KeyCode::VolumeMute,
+            WinitKeyCode::AudioVolumeUp => KeyCode::VolumeUp,
+            WinitKeyCode::BrowserBack => KeyCode::BrowserBack,
+            WinitKeyCode::BrowserForward => KeyCode::BrowserForward,
+            WinitKeyCode::BrowserRefresh => KeyCode::BrowserRefresh,
+            WinitKeyCode::BrowserStop => KeyCode::BrowserStop,
+            WinitKeyCode::BrowserSearch => KeyCode::BrowserSearch,
+            WinitKeyCode::BrowserFavorites => KeyCode::BrowserFavorites,
+            WinitKeyCode::BrowserHome => KeyCode::BrowserHome,
+            WinitKeyCode::LaunchMail => KeyCode::LaunchMail,
+            WinitKeyCode::LaunchApp1 => KeyCode::LaunchApp1,
+            WinitKeyCode::LaunchApp2 => KeyCode::LaunchApp2,
+            WinitKeyCode::Eject => KeyCode::Eject,
+            WinitKeyCode::Power => KeyCode::Power,
+            WinitKeyCode::Sleep => KeyCode::Sleep,
+            WinitKeyCode::WakeUp => KeyCode::WakeUp,
+
             _ => KeyCode::Unknown,
         }
     }
 }
+
+/// Converts to the equivalent Winit key code.
+///
+/// [`KeyCode::Unknown`] has no equivalent, and is returned as the error.  Convert to a
+/// [`PhysicalKey`] instead to handle unknown keys.
+impl TryFrom<KeyCode> for WinitKeyCode {
+    type Error = KeyCode;
+
+    fn try_from(keycode: KeyCode) -> Result<Self, Self::Error> {
+        let winit_keycode = match keycode {
+            KeyCode::Escape => WinitKeyCode::Escape,
+            KeyCode::F1 => WinitKeyCode::F1,
+            KeyCode::F2 => WinitKeyCode::F2,
+            KeyCode::F3 => WinitKeyCode::F3,
+            KeyCode::F4 => WinitKeyCode::F4,
+            KeyCode::F5 => WinitKeyCode::F5,
+            KeyCode::F6 => WinitKeyCode::F6,
+            KeyCode::F7 => WinitKeyCode::F7,
+            KeyCode::F8 => WinitKeyCode::F8,
+            KeyCode::F9 => WinitKeyCode::F9,
+            KeyCode::F10 => WinitKeyCode::F10,
+            KeyCode::F11 => WinitKeyCode::F11,
+            KeyCode::F12 => WinitKeyCode::F12,
+            KeyCode::PrintScreen => WinitKeyCode::PrintScreen,
+            KeyCode::ScrollLock => WinitKeyCode::ScrollLock,
+            KeyCode::Pause => WinitKeyCode::Pause,
+
+            KeyCode::Grave => WinitKeyCode::Backquote,
+            KeyCode::Num1 => WinitKeyCode::Digit1,
+            KeyCode::Num2 => WinitKeyCode::Digit2,
+            KeyCode::Num3 => WinitKeyCode::Digit3,
+            KeyCode::Num4 => WinitKeyCode::Digit4,
+            KeyCode::Num5 => WinitKeyCode::Digit5,
+            KeyCode::Num6 => WinitKeyCode::Digit6,
+            KeyCode::Num7 => WinitKeyCode::Digit7,
+            KeyCode::Num8 => WinitKeyCode::Digit8,
+            KeyCode::Num9 => WinitKeyCode::Digit9,
+            KeyCode::Num0 => WinitKeyCode::Digit0,
+            KeyCode::Minus => WinitKeyCode::Minus,
+            KeyCode::Equals => WinitKeyCode::Equal,
+            KeyCode::BackSlash => WinitKeyCode::Backslash,
+            KeyCode::Backspace => WinitKeyCode::Backspace,
+            KeyCode::Insert => WinitKeyCode::Insert,
+            KeyCode::Home => WinitKeyCode::Home,
+            KeyCode::PageUp => WinitKeyCode::PageUp,
+            KeyCode::NumLock => WinitKeyCode::NumLock,
+            KeyCode::NumpadDivide => WinitKeyCode::NumpadDivide,
+            KeyCode::NumpadMultiply => WinitKeyCode::NumpadMultiply,
+            KeyCode::NumpadSubtract => WinitKeyCode::NumpadSubtract,
+
+            KeyCode::Tab => WinitKeyCode::Tab,
+            KeyCode::Q => WinitKeyCode::KeyQ,
+            KeyCode::W => WinitKeyCode::KeyW,
+            KeyCode::E => WinitKeyCode::KeyE,
+            KeyCode::R => WinitKeyCode::KeyR,
+            KeyCode::T => WinitKeyCode::KeyT,
+            KeyCode::Y => WinitKeyCode::KeyY,
+            KeyCode::U => WinitKeyCode::KeyU,
+            KeyCode::I => WinitKeyCode::KeyI,
+            KeyCode::O => WinitKeyCode::KeyO,
+            KeyCode::P => WinitKeyCode::KeyP,
+            KeyCode::LeftBracket => WinitKeyCode::BracketLeft,
+            KeyCode::RightBracket => WinitKeyCode::BracketRight,
+            KeyCode::Delete => WinitKeyCode::Delete,
+            KeyCode::End => WinitKeyCode::End,
+            KeyCode::PageDown => WinitKeyCode::PageDown,
+            KeyCode::Numpad7 => WinitKeyCode::Numpad7,
+            KeyCode::Numpad8 => WinitKeyCode::Numpad8,
+            KeyCode::Numpad9 => WinitKeyCode::Numpad9,
+            KeyCode::NumpadAdd => WinitKeyCode::NumpadAdd,
+
+            KeyCode::CapsLock => WinitKeyCode::CapsLock,
+            KeyCode::A => WinitKeyCode::KeyA,
+            KeyCode::S => WinitKeyCode::KeyS,
+            KeyCode::D => WinitKeyCode::KeyD,
+            KeyCode::F => WinitKeyCode::KeyF,
+            KeyCode::G => WinitKeyCode::KeyG,
+            KeyCode::H => WinitKeyCode::KeyH,
+            KeyCode::J => WinitKeyCode::KeyJ,
+            KeyCode::K => WinitKeyCode::KeyK,
+            KeyCode::L => WinitKeyCode::KeyL,
+            KeyCode::Semicolon => WinitKeyCode::Semicolon,
+            KeyCode::Quote => WinitKeyCode::Quote,
+            KeyCode::Enter => WinitKeyCode::Enter,
+            KeyCode::Numpad4 => WinitKeyCode::Numpad4,
+            KeyCode::Numpad5 => WinitKeyCode::Numpad5,
+            KeyCode::Numpad6 => WinitKeyCode::Numpad6,
+
+            KeyCode::LeftShift => WinitKeyCode::ShiftLeft,
+            KeyCode::Z => WinitKeyCode::KeyZ,
+            KeyCode::X => WinitKeyCode::KeyX,
+            KeyCode::C => WinitKeyCode::KeyC,
+            KeyCode::V => WinitKeyCode::KeyV,
+            KeyCode::B => WinitKeyCode::KeyB,
+            KeyCode::N => WinitKeyCode::KeyN,
+            KeyCode::M => WinitKeyCode::KeyM,
+            KeyCode::Comma => WinitKeyCode::Comma,
+            KeyCode::Period => WinitKeyCode::Period,
+            KeyCode::ForwardSlash => WinitKeyCode::Slash,
+            KeyCode::RightShift => WinitKeyCode::ShiftRight,
+            KeyCode::UpArrow => WinitKeyCode::ArrowUp,
+            KeyCode::Numpad1 => WinitKeyCode::Numpad1,
+            KeyCode::Numpad2 => WinitKeyCode::Numpad2,
+            KeyCode::Numpad3 => WinitKeyCode::Numpad3,
+            KeyCode::NumpadEnter => WinitKeyCode::NumpadEnter,
+
+            KeyCode::LeftControl => WinitKeyCode::ControlLeft,
+            KeyCode::LeftSuper => WinitKeyCode::SuperLeft,
+            KeyCode::LeftAlt => WinitKeyCode::AltLeft,
+            KeyCode::Space => WinitKeyCode::Space,
+            KeyCode::RightAlt => WinitKeyCode::AltRight,
+            KeyCode::RightSuper => WinitKeyCode::SuperRight,
+            KeyCode::RightControl => WinitKeyCode::ControlRight,
+            KeyCode::LeftArrow => WinitKeyCode::ArrowLeft,
+            KeyCode::DownArrow => WinitKeyCode::ArrowDown,
+            KeyCode::RightArrow => WinitKeyCode::ArrowRight,
+            KeyCode::Numpad0 => WinitKeyCode::Numpad0,
+            KeyCode::NumpadDecimal => WinitKeyCode::NumpadDecimal,
+
+            KeyCode::F13 => WinitKeyCode::F13,
+            KeyCode::F14 => WinitKeyCode::F14,
+            KeyCode::F15 => WinitKeyCode::F15,
+            KeyCode::F16 => WinitKeyCode::F16,
+            KeyCode::F17 => WinitKeyCode::F17,
+            KeyCode::F18 => WinitKeyCode::F18,
+            KeyCode::F19 => WinitKeyCode::F19,
+            KeyCode::F20 => WinitKeyCode::F20,
+            KeyCode::F21 => WinitKeyCode::F21,
+            KeyCode::F22 => WinitKeyCode::F22,
+            KeyCode::F23 => WinitKeyCode::F23,
+            KeyCode::F24 => WinitKeyCode::F24,
+            KeyCode::F25 => WinitKeyCode::F25,
+            KeyCode::F26 => WinitKeyCode::F26,
+            KeyCode::F27 => WinitKeyCode::F27,
+            KeyCode::F28 => WinitKeyCode::F28,
+            KeyCode::F29 => WinitKeyCode::F29,
+            KeyCode::F30 => WinitKeyCode::F30,
+            KeyCode::F31 => WinitKeyCode::F31,
+            KeyCode::F32 => WinitKeyCode::F32,
+            KeyCode::F33 => WinitKeyCode::F33,
+            KeyCode::F34 => WinitKeyCode::F34,
+            KeyCode::F35 => WinitKeyCode::F35,
+
+            KeyCode::NumpadBackspace => WinitKeyCode::NumpadBackspace,
+            KeyCode::NumpadClear => WinitKeyCode::NumpadClear,
+            KeyCode::NumpadClearEntry => WinitKeyCode::NumpadClearEntry,
+            KeyCode::NumpadComma => WinitKeyCode::NumpadComma,
+            KeyCode::NumpadEquals => WinitKeyCode::NumpadEqual,
+            KeyCode::NumpadHash => WinitKeyCode::NumpadHash,
+            KeyCode::NumpadStar => WinitKeyCode::NumpadStar,
+            KeyCode::NumpadLeftParen => WinitKeyCode::NumpadParenLeft,
+            KeyCode::NumpadRightParen => WinitKeyCode::NumpadParenRight,
+            KeyCode::NumpadMemoryAdd => WinitKeyCode::NumpadMemoryAdd,
+            KeyCode::NumpadMemoryClear => WinitKeyCode::NumpadMemoryClear,
+            KeyCode::NumpadMemoryRecall => WinitKeyCode::NumpadMemoryRecall,
+            KeyCode::NumpadMemoryStore => WinitKeyCode::NumpadMemoryStore,
+            KeyCode::NumpadMemorySubtract => WinitKeyCode::NumpadMemorySubtract,
+
+            KeyCode::IntlBackslash => WinitKeyCode::IntlBackslash,
+            KeyCode::IntlRo => WinitKeyCode::IntlRo,
+            KeyCode::IntlYen => WinitKeyCode::IntlYen,
+            KeyCode::Convert => WinitKeyCode::Convert,
+            KeyCode::NonConvert => WinitKeyCode::NonConvert,
+            KeyCode::KanaMode => WinitKeyCode::KanaMode,
+            KeyCode::Hiragana => WinitKeyCode::Hiragana,
+            KeyCode::Katakana => WinitKeyCode::Katakana,
+            KeyCode::Lang1 => WinitKeyCode::Lang1,
+            KeyCode::Lang2 => WinitKeyCode::Lang2,
+            KeyCode::Lang3 => WinitKeyCode::Lang3,
+            KeyCode::Lang4 => WinitKeyCode::Lang4,
+            KeyCode::Lang5 => WinitKeyCode::Lang5,
+
+            KeyCode::ContextMenu => WinitKeyCode::ContextMenu,
+            KeyCode::Fn => WinitKeyCode::Fn,
+            KeyCode::FnLock => WinitKeyCode::FnLock,
+            KeyCode::Help => WinitKeyCode::Help,
+            KeyCode::Meta => WinitKeyCode::Meta,
+            KeyCode::Hyper => WinitKeyCode::Hyper,
+            KeyCode::Turbo => WinitKeyCode::Turbo,
+            KeyCode::Abort => WinitKeyCode::Abort,
+            KeyCode::Resume => WinitKeyCode::Resume,
+            KeyCode::Suspend => WinitKeyCode::Suspend,
+            KeyCode::Again => WinitKeyCode::Again,
+            KeyCode::Copy => WinitKeyCode::Copy,
+            KeyCode::Cut => WinitKeyCode::Cut,
+            KeyCode::Paste => WinitKeyCode::Paste,
+            KeyCode::Find => WinitKeyCode::Find,
+            KeyCode::Open => WinitKeyCode::Open,
+            KeyCode::Props => WinitKeyCode::Props,
+            KeyCode::Select => WinitKeyCode::Select,
+            KeyCode::Undo => WinitKeyCode::Undo,
+
+            KeyCode::MediaPlayPause => WinitKeyCode::MediaPlayPause,
+            KeyCode::MediaStop => WinitKeyCode::MediaStop,
+            KeyCode::MediaTrackNext => WinitKeyCode::MediaTrackNext,
+            KeyCode::MediaTrackPrevious => WinitKeyCode::MediaTrackPrevious,
+            KeyCode::MediaSelect => WinitKeyCode::MediaSelect,
+            KeyCode::VolumeDown => WinitKeyCode::AudioVolumeDown,
+            KeyCode::VolumeMute => WinitKeyCode::AudioVolumeMute,
+            KeyCode::VolumeUp => WinitKeyCode::AudioVolumeUp,
+            KeyCode::BrowserBack => WinitKeyCode::BrowserBack,
+            KeyCode::BrowserForward => WinitKeyCode::BrowserForward,
+            KeyCode::BrowserRefresh => WinitKeyCode::BrowserRefresh,
+            KeyCode::BrowserStop => WinitKeyCode::BrowserStop,
+            KeyCode::BrowserSearch => WinitKeyCode::BrowserSearch,
+            KeyCode::BrowserFavorites => WinitKeyCode::BrowserFavorites,
+            KeyCode::BrowserHome => WinitKeyCode::BrowserHome,
+            KeyCode::LaunchMail => WinitKeyCode::LaunchMail,
+            KeyCode::LaunchApp1 => WinitKeyCode::LaunchApp1,
+            KeyCode::LaunchApp2 => WinitKeyCode::LaunchApp2,
+            KeyCode::Eject => WinitKeyCode::Eject,
+            KeyCode::Power => WinitKeyCode::Power,
+            KeyCode::Sleep => WinitKeyCode::Sleep,
+            KeyCode::WakeUp => WinitKeyCode::WakeUp,
+
+            KeyCode::Unknown => return Err(keycode),
+        };
+        Ok(winit_keycode)
+    }
+}
+
+#[cfg(test)]
+mod winit_tests {
+    use super::*;
+
+    const WINIT_KEYCODES: &[WinitKeyCode] = &[
+        WinitKeyCode::Backquote,
+        WinitKeyCode::Backslash,
+        WinitKeyCode::BracketLeft,
+        WinitKeyCode::BracketRight,
+        WinitKeyCode::Comma,
+        WinitKeyCode::Digit0,
+        WinitKeyCode::Digit1,
+        WinitKeyCode::Digit2,
+        WinitKeyCode::Digit3,
+        WinitKeyCode::Digit4,
+        WinitKeyCode::Digit5,
+        WinitKeyCode::Digit6,
+        WinitKeyCode::Digit7,
+        WinitKeyCode::Digit8,
+        WinitKeyCode::Digit9,
+        WinitKeyCode::Equal,
+        WinitKeyCode::IntlBackslash,
+        WinitKeyCode::IntlRo,
+        WinitKeyCode::IntlYen,
+        WinitKeyCode::KeyA,
+        WinitKeyCode::KeyB,
+        WinitKeyCode::KeyC,
+        WinitKeyCode::KeyD,
+        WinitKeyCode::KeyE,
+        WinitKeyCode::KeyF,
+        WinitKeyCode::KeyG,
+        WinitKeyCode::KeyH,
+        WinitKeyCode::KeyI,
+        WinitKeyCode::KeyJ,
+        WinitKeyCode::KeyK,
+        WinitKeyCode::KeyL,
+        WinitKeyCode::KeyM,
+        WinitKeyCode::KeyN,
+        WinitKeyCode::KeyO,
+        WinitKeyCode::KeyP,
+        WinitKeyCode::KeyQ,
+        WinitKeyCode::KeyR,
+        WinitKeyCode::KeyS,
+        WinitKeyCode::KeyT,
+        WinitKeyCode::KeyU,
+        WinitKeyCode::KeyV,
+        WinitKeyCode::KeyW,
+        WinitKeyCode::KeyX,
+        WinitKeyCode::KeyY,
+        WinitKeyCode::KeyZ,
+        WinitKeyCode::Minus,
+        WinitKeyCode::Period,
+        WinitKeyCode::Quote,
+        WinitKeyCode::Semicolon,
+        WinitKeyCode::Slash,
+        WinitKeyCode::AltLeft,
+        WinitKeyCode::AltRight,
+        WinitKeyCode::Backspace,
+        WinitKeyCode::CapsLock,
+        WinitKeyCode::ContextMenu,
+        WinitKeyCode::ControlLeft,
+        WinitKeyCode::ControlRight,
+        WinitKeyCode::Enter,
+        WinitKeyCode::SuperLeft,
+        WinitKeyCode::SuperRight,
+        WinitKeyCode::ShiftLeft,
+        WinitKeyCode::ShiftRight,
+        WinitKeyCode::Space,
+        WinitKeyCode::Tab,
+        WinitKeyCode::Convert,
+        WinitKeyCode::KanaMode,
+        WinitKeyCode::Lang1,
+        WinitKeyCode::Lang2,
+        WinitKeyCode::Lang3,
+        WinitKeyCode::Lang4,
+        WinitKeyCode::Lang5,
+        WinitKeyCode::NonConvert,
+        WinitKeyCode::Delete,
+        WinitKeyCode::End,
+        WinitKeyCode::Help,
+        WinitKeyCode::Home,
+        WinitKeyCode::Insert,
+        WinitKeyCode::PageDown,
+        WinitKeyCode::PageUp,
+        WinitKeyCode::ArrowDown,
+        WinitKeyCode::ArrowLeft,
+        WinitKeyCode::ArrowRight,
+        WinitKeyCode::ArrowUp,
+        WinitKeyCode::NumLock,
+        WinitKeyCode::Numpad0,
+        WinitKeyCode::Numpad1,
+        WinitKeyCode::Numpad2,
+        WinitKeyCode::Numpad3,
+        WinitKeyCode::Numpad4,
+        WinitKeyCode::Numpad5,
+        WinitKeyCode::Numpad6,
+        WinitKeyCode::Numpad7,
+        WinitKeyCode::Numpad8,
+        WinitKeyCode::Numpad9,
+        WinitKeyCode::NumpadAdd,
+        WinitKeyCode::NumpadBackspace,
+        WinitKeyCode::NumpadClear,
+        WinitKeyCode::NumpadClearEntry,
+        WinitKeyCode::NumpadComma,
+        WinitKeyCode::NumpadDecimal,
+        WinitKeyCode::NumpadDivide,
+        WinitKeyCode::NumpadEnter,
+        WinitKeyCode::NumpadEqual,
+        WinitKeyCode::NumpadHash,
+        WinitKeyCode::NumpadMemoryAdd,
+        WinitKeyCode::NumpadMemoryClear,
+        WinitKeyCode::NumpadMemoryRecall,
+        WinitKeyCode::NumpadMemoryStore,
+        WinitKeyCode::NumpadMemorySubtract,
+        WinitKeyCode::NumpadMultiply,
+        WinitKeyCode::NumpadParenLeft,
+        WinitKeyCode::NumpadParenRight,
+        WinitKeyCode::NumpadStar,
+        WinitKeyCode::NumpadSubtract,
+        WinitKeyCode::Escape,
+        WinitKeyCode::Fn,
+        WinitKeyCode::FnLock,
+        WinitKeyCode::PrintScreen,
+        WinitKeyCode::ScrollLock,
+        WinitKeyCode::Pause,
+        WinitKeyCode::BrowserBack,
+        WinitKeyCode::BrowserFavorites,
+        WinitKeyCode::BrowserForward,
+        WinitKeyCode::BrowserHome,
+        WinitKeyCode::BrowserRefresh,
+        WinitKeyCode::BrowserSearch,
+        WinitKeyCode::BrowserStop,
+        WinitKeyCode::Eject,
+        WinitKeyCode::LaunchApp1,
+        WinitKeyCode::LaunchApp2,
+        WinitKeyCode::LaunchMail,
+        WinitKeyCode::MediaPlayPause,
+        WinitKeyCode::MediaSelect,
+        WinitKeyCode::MediaStop,
+        WinitKeyCode::MediaTrackNext,
+        WinitKeyCode::MediaTrackPrevious,
+        WinitKeyCode::Power,
+        WinitKeyCode::Sleep,
+        WinitKeyCode::AudioVolumeDown,
+        WinitKeyCode::AudioVolumeMute,
+        WinitKeyCode::AudioVolumeUp,
+        WinitKeyCode::WakeUp,
+        WinitKeyCode::Meta,
+        WinitKeyCode::Hyper,
+        WinitKeyCode::Turbo,
+        WinitKeyCode::Abort,
+        WinitKeyCode::Resume,
+        WinitKeyCode::Suspend,
+        WinitKeyCode::Again,
+        WinitKeyCode::Copy,
+        WinitKeyCode::Cut,
+        WinitKeyCode::Find,
+        WinitKeyCode::Open,
+        WinitKeyCode::Paste,
+        WinitKeyCode::Props,
+        WinitKeyCode::Select,
+        WinitKeyCode::Undo,
+        WinitKeyCode::Hiragana,
+        WinitKeyCode::Katakana,
+        WinitKeyCode::F1,
+        WinitKeyCode::F2,
+        WinitKeyCode::F3,
+        WinitKeyCode::F4,
+        WinitKeyCode::F5,
+        WinitKeyCode::F6,
+        WinitKeyCode::F7,
+        WinitKeyCode::F8,
+        WinitKeyCode::F9,
+        WinitKeyCode::F10,
+        WinitKeyCode::F11,
+        WinitKeyCode::F12,
+        WinitKeyCode::F13,
+        WinitKeyCode::F14,
+        WinitKeyCode::F15,
+        WinitKeyCode::F16,
+        WinitKeyCode::F17,
+        WinitKeyCode::F18,
+        WinitKeyCode::F19,
+        WinitKeyCode::F20,
+        WinitKeyCode::F21,
+        WinitKeyCode::F22,
+        WinitKeyCode::F23,
+        WinitKeyCode::F24,
+        WinitKeyCode::F25,
+        WinitKeyCode::F26,
+        WinitKeyCode::F27,
+        WinitKeyCode::F28,
+        WinitKeyCode::F29,
+        WinitKeyCode::F30,
+        WinitKeyCode::F31,
+        WinitKeyCode::F32,
+        WinitKeyCode::F33,
+        WinitKeyCode::F34,
+        WinitKeyCode::F35,
+    ];
+
+    #[test]
+    fn should_round_trip_every_keycode() {
+        for keycode in KeyCode::ALL {
+            let winit_keycode = WinitKeyCode::try_from(*keycode).unwrap();
+            assert_eq!(KeyCode::from(winit_keycode), *keycode, "{winit_keycode:?}");
+        }
+        assert_eq!(
+            WinitKeyCode::try_from(KeyCode::Unknown),
+            Err(KeyCode::Unknown)
+        );
+        assert_eq!(
+            KeyCode::from(PhysicalKey::from(KeyCode::Unknown)),
+            KeyCode::Unknown
+        );
+    }
+
+    #[test]
+    fn should_round_trip_every_winit_keycode() {
+        for winit_keycode in WINIT_KEYCODES {
+            let keycode = KeyCode::from(*winit_keycode);
+            assert_ne!(keycode, KeyCode::Unknown, "{winit_keycode:?} is not mapped");
+            assert_eq!(WinitKeyCode::try_from(keycode), Ok(*winit_keycode));
+        }
+        assert_eq!(WINIT_KEYCODES.len(), KeyCode::ALL.len());
+    }
+}
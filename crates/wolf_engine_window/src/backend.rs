@@ -9,7 +9,10 @@ use crate::WindowContext;
 pub mod event {
     use wolf_engine_events::dynamic::Event;
 
-    use crate::{Uuid, WindowSettings};
+    use crate::{
+        dpi::Size, CursorGrabMode, CursorIcon, CustomCursor, FullscreenMode, Monitor, Uuid,
+        WindowSettings,
+    };
 
     pub use crate::context::WindowContextEventSender;
 
@@ -31,15 +34,144 @@ pub mod event {
         /// Handling this event is optional, because not all platforms / window systems support it.
         WindowRedrawRequested(Uuid),
 
-        /// Emitted when a window is resized.
+        /// Emitted when a window is resized, with its new size in physical pixels.
         WindowResized(Uuid, u32, u32),
 
+        /// Emitted when a window's scale factor has changed.
+        WindowScaleFactorChanged(Uuid, f64),
+
+        /// Emitted when the front-end is requesting a new size for a window.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowResizeRequested(Uuid, Size),
+
+        /// Emitted when a window is moved.
+        WindowMoved(Uuid, i32, i32),
+
+        /// Emitted when a window gains, or loses keyboard focus.
+        WindowFocused(Uuid, bool),
+
+        /// Emitted when a window is minimized, or un-minimized.
+        WindowMinimized(Uuid, bool),
+
+        /// Emitted when a window is maximized, or un-maximized.
+        WindowMaximized(Uuid, bool),
+
+        /// Emitted when a window becomes fully hidden by other windows, or visible again.
+        WindowOccluded(Uuid, bool),
+
+        /// Emitted when the front-end is requesting a new position for a window.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowMoveRequested(Uuid, i32, i32),
+
+        /// Emitted when the front-end is requesting a window be shown, or hidden.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowVisibilityRequested(Uuid, bool),
+
+        /// Emitted when the front-end is requesting a new minimum size for a window.
+        ///
+        /// `None` removes the limit.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowMinSizeRequested(Uuid, Option<Size>),
+
+        /// Emitted when the front-end is requesting a new maximum size for a window.
+        ///
+        /// `None` removes the limit.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowMaxSizeRequested(Uuid, Option<Size>),
+
+        /// Emitted when the front-end is requesting a window be minimized.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowMinimizeRequested(Uuid),
+
+        /// Emitted when the front-end is requesting a window be maximized.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowMaximizeRequested(Uuid),
+
+        /// Emitted when the front-end is requesting a window be restored from being minimized, or
+        /// maximized.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowRestoreRequested(Uuid),
+
+        /// Emitted when the front-end is requesting a new fullscreen-mode for a window.
+        ///
+        /// `None` requests the window leave fullscreen.  Once the request has been handled, the
+        /// backend should send a [`WindowContextEvent::WindowFullscreenModeChanged`] event with
+        /// the mode which was actually applied.
+        WindowFullscreenModeRequested(Uuid, Option<FullscreenMode>),
+
+        /// Emitted when a window's fullscreen-mode has changed.
+        WindowFullscreenModeChanged(Uuid, Option<FullscreenMode>),
+
         /// Emitted when a new window is created.
         WindowCreated(Uuid, WindowSettings),
 
         /// Emitted when all copies of a window have been dropped, and it should be closed.
         WindowClosed(Uuid),
 
+        /// Emitted when the front-end is requesting the cursor be shown, or hidden while it's over
+        /// a window.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowCursorVisibilityRequested(Uuid, bool),
+
+        /// Emitted when the front-end is requesting the cursor be grabbed by a window.
+        ///
+        /// If the request is refused, the backend should emit a
+        /// [`WindowEvent::RequestFailed`](crate::WindowEvent::RequestFailed) event.
+        WindowCursorGrabRequested(Uuid, CursorGrabMode),
+
+        /// Emitted when the front-end is requesting a new cursor icon for a window.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowCursorIconRequested(Uuid, CursorIcon),
+
+        /// Emitted when the front-end is requesting a custom cursor for a window.
+        ///
+        /// # Portability
+        ///
+        /// Handling this event is optional, because not all platforms / window systems support it.
+        WindowCustomCursorRequested(Uuid, CustomCursor),
+
+        /// Emitted when the front-end is requesting the cursor be moved to a position in a window.
+        ///
+        /// If the request is refused, the backend should emit a
+        /// [`WindowEvent::RequestFailed`](crate::WindowEvent::RequestFailed) event.
+        WindowCursorMoveRequested(Uuid, f32, f32),
+
+        /// Emitted when the set of connected monitors has changed, or is first known.
+        ///
+        /// Backends which aren't notified when monitors are connected, or disconnected, should
+        /// check for changes whenever they have a chance to, such as when a window is moved.
+        MonitorsChanged(Vec<Monitor>),
+
         /// Emitted when the main-loop should exit.
         Exited,
     }
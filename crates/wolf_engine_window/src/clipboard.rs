@@ -0,0 +1,97 @@
+use std::sync::Mutex;
+
+use crate::{RgbaImage, WindowError};
+
+/// Provides access to the system clipboard.
+///
+/// Clipboards are provided by the window system, and accessed through
+/// [`WindowContext::clipboard()`](crate::WindowContext::clipboard).  Only text is required,
+/// image support is optional, and reports [`WindowError::UnsupportedError`] by default.
+pub trait Clipboard: Send + Sync {
+    /// Get the text on the clipboard, or `None` if it doesn't contain any text.
+    fn text(&self) -> Result<Option<String>, WindowError>;
+
+    /// Replace the contents of the clipboard with some text.
+    fn set_text(&self, text: &str) -> Result<(), WindowError>;
+
+    /// Get the image on the clipboard, or `None` if it doesn't contain an image.
+    fn image(&self) -> Result<Option<RgbaImage>, WindowError> {
+        Err(WindowError::UnsupportedError(
+            "This clipboard does not support images".to_string(),
+        ))
+    }
+
+    /// Replace the contents of the clipboard with an image.
+    fn set_image(&self, _image: &RgbaImage) -> Result<(), WindowError> {
+        Err(WindowError::UnsupportedError(
+            "This clipboard does not support images".to_string(),
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ClipboardContents {
+    Text(String),
+    Image(RgbaImage),
+}
+
+/// A [`Clipboard`] which stores its contents in memory, for headless backends, and tests.
+///
+/// The contents aren't shared with other applications.
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    contents: Mutex<Option<ClipboardContents>>,
+}
+
+impl Clipboard for MemoryClipboard {
+    fn text(&self) -> Result<Option<String>, WindowError> {
+        match &*self.contents.lock().unwrap() {
+            Some(ClipboardContents::Text(text)) => Ok(Some(text.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), WindowError> {
+        *self.contents.lock().unwrap() = Some(ClipboardContents::Text(text.to_string()));
+        Ok(())
+    }
+
+    fn image(&self) -> Result<Option<RgbaImage>, WindowError> {
+        match &*self.contents.lock().unwrap() {
+            Some(ClipboardContents::Image(image)) => Ok(Some(image.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn set_image(&self, image: &RgbaImage) -> Result<(), WindowError> {
+        *self.contents.lock().unwrap() = Some(ClipboardContents::Image(image.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod memory_clipboard_tests {
+    use super::*;
+
+    #[test]
+    fn should_store_text() {
+        let clipboard = MemoryClipboard::default();
+        assert_eq!(clipboard.text(), Ok(None));
+
+        clipboard.set_text("Hello, World!").unwrap();
+
+        assert_eq!(clipboard.text(), Ok(Some("Hello, World!".to_string())));
+    }
+
+    #[test]
+    fn should_replace_text_with_images() {
+        let clipboard = MemoryClipboard::default();
+        let image = RgbaImage::new(1, 1, vec![255; 4]).unwrap();
+        clipboard.set_text("Hello, World!").unwrap();
+
+        clipboard.set_image(&image).unwrap();
+
+        assert_eq!(clipboard.image(), Ok(Some(image)));
+        assert_eq!(clipboard.text(), Ok(None));
+    }
+}
@@ -8,20 +8,36 @@ use wolf_engine_events::{
     dynamic::AnyEvent, mpsc::MpscEventSender, EventSender, ReceiverDroppedError,
 };
 
-use crate::{backend::event::WindowContextEvent, Window, WindowSettings, WindowState};
+use crate::{
+    backend::event::WindowContextEvent, Clipboard, MemoryClipboard, Monitor, Window,
+    WindowSettings, WindowState,
+};
 
 #[derive(Clone)]
 /// A link to the window system.
 pub struct WindowContext {
     pub(crate) event_sender: MpscEventSender<AnyEvent>,
     window_states: Arc<RwLock<HashMap<Uuid, Weak<WindowState>>>>,
+    monitors: Arc<RwLock<Vec<Monitor>>>,
+    clipboard: Arc<dyn Clipboard>,
 }
 
 impl WindowContext {
+    /// Create a new context, with a [`MemoryClipboard`].
     pub fn new(event_sender: MpscEventSender<AnyEvent>) -> (Self, WindowContextEventSender) {
+        Self::with_clipboard(event_sender, MemoryClipboard::default())
+    }
+
+    /// Create a new context, using the window system's [`Clipboard`].
+    pub fn with_clipboard<C: Clipboard + 'static>(
+        event_sender: MpscEventSender<AnyEvent>,
+        clipboard: C,
+    ) -> (Self, WindowContextEventSender) {
         let context = Self {
             event_sender,
             window_states: Arc::new(RwLock::new(HashMap::new())),
+            monitors: Arc::new(RwLock::new(Vec::new())),
+            clipboard: Arc::new(clipboard),
         };
         let event_sender = WindowContextEventSender::new(context.clone());
         (context, event_sender)
@@ -42,6 +58,30 @@ impl WindowContext {
         window
     }
 
+    /// Get the monitors connected to the system.
+    ///
+    /// The list is provided by the window system, and will be empty until it has started.  Some
+    /// window systems only notice monitors being connected, or disconnected, when a window is
+    /// moved, or changes scale factor, so the list may be briefly out of date.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.monitors.read().unwrap().clone()
+    }
+
+    /// Get the system's primary monitor, if it's known.
+    pub fn primary_monitor(&self) -> Option<Monitor> {
+        self.monitors
+            .read()
+            .unwrap()
+            .iter()
+            .find(|monitor| monitor.is_primary)
+            .cloned()
+    }
+
+    /// Get the system clipboard.
+    pub fn clipboard(&self) -> Arc<dyn Clipboard> {
+        self.clipboard.clone()
+    }
+
     /// Stops the event loop.
     pub fn exit(&self) {
         self.event_sender
@@ -50,10 +90,49 @@ impl WindowContext {
     }
 
     fn process_event(&self, event: WindowContextEvent) {
-        if let WindowContextEvent::WindowResized(uuid, width, height) = event {
-            self.with_window_state_mut(uuid, |window_state| {
-                window_state.resize(width, height);
-            })
+        match event {
+            WindowContextEvent::WindowResized(uuid, width, height) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    window_state.resize(width, height);
+                })
+            }
+            WindowContextEvent::WindowScaleFactorChanged(uuid, scale_factor) => self
+                .with_window_state_mut(uuid, |window_state| {
+                    window_state.set_scale_factor(scale_factor);
+                }),
+            WindowContextEvent::WindowMoved(uuid, x, y) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    window_state.set_position(x, y);
+                })
+            }
+            WindowContextEvent::WindowFocused(uuid, is_focused) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    *window_state.is_focused.write().unwrap() = is_focused;
+                })
+            }
+            WindowContextEvent::WindowMinimized(uuid, is_minimized) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    *window_state.is_minimized.write().unwrap() = is_minimized;
+                })
+            }
+            WindowContextEvent::WindowMaximized(uuid, is_maximized) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    *window_state.is_maximized.write().unwrap() = is_maximized;
+                })
+            }
+            WindowContextEvent::WindowOccluded(uuid, is_occluded) => {
+                self.with_window_state_mut(uuid, |window_state| {
+                    *window_state.is_occluded.write().unwrap() = is_occluded;
+                })
+            }
+            WindowContextEvent::WindowFullscreenModeChanged(uuid, fullscreen_mode) => self
+                .with_window_state_mut(uuid, |window_state| {
+                    window_state.set_fullscreen_mode(fullscreen_mode);
+                }),
+            WindowContextEvent::MonitorsChanged(monitors) => {
+                *self.monitors.write().unwrap() = monitors;
+            }
+            _ => (),
         }
     }
 
@@ -109,13 +188,18 @@ impl EventSender<WindowContextEvent> for WindowContextEventSender {
 }
 
 #[cfg(test)]
+#[allow(clippy::collapsible_match, clippy::single_match)]
 mod window_context_tests {
     use wolf_engine_events::{
         mpsc::{self, MpscEventReceiver},
         EventReceiver,
     };
 
-    use crate::backend::event::WindowContextEvent;
+    use crate::{
+        backend::event::WindowContextEvent,
+        dpi::{LogicalSize, PhysicalSize},
+        CursorGrabMode, CursorIcon, CustomCursor, FullscreenMode, MonitorId, RgbaImage, VideoMode,
+    };
 
     use super::*;
 
@@ -243,7 +327,254 @@ mod window_context_tests {
             .send_event(WindowContextEvent::WindowResized(window.id(), 800, 600))
             .unwrap();
 
-        assert_eq!(window.size(), (800, 600), "The window was not resized");
+        assert_eq!(
+            window.size(),
+            PhysicalSize::new(800, 600),
+            "The window was not resized"
+        );
+    }
+
+    #[test]
+    fn should_emit_window_control_requests() {
+        let (_, mut event_receiver, context, _context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        let uuid = window.id();
+        while event_receiver.next_event().is_some() {}
+
+        window.set_size(LogicalSize::new(400.0, 300.0));
+        window.set_position((10, 20));
+        window.set_visible(false);
+        window.set_min_size(Some((100, 100).into()));
+        window.set_max_size(None);
+        window.minimize();
+        window.maximize();
+        window.restore();
+
+        let mut context_events = Vec::new();
+        while let Some(event) = event_receiver.next_event() {
+            if let Some(context_event) = event.downcast_ref::<WindowContextEvent>() {
+                context_events.push(context_event.to_owned());
+            }
+        }
+        assert_eq!(
+            context_events,
+            vec![
+                WindowContextEvent::WindowResizeRequested(
+                    uuid,
+                    LogicalSize::new(400.0, 300.0).into()
+                ),
+                WindowContextEvent::WindowMoveRequested(uuid, 10, 20),
+                WindowContextEvent::WindowVisibilityRequested(uuid, false),
+                WindowContextEvent::WindowMinSizeRequested(uuid, Some((100, 100).into())),
+                WindowContextEvent::WindowMaxSizeRequested(uuid, None),
+                WindowContextEvent::WindowMinimizeRequested(uuid),
+                WindowContextEvent::WindowMaximizeRequested(uuid),
+                WindowContextEvent::WindowRestoreRequested(uuid),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_share_the_clipboard_between_context_clones() {
+        let (_, _event_receiver, context, _context_event_sender) = test_init();
+        let context_clone = context.clone();
+
+        context.clipboard().set_text("Hello, World!").unwrap();
+
+        assert_eq!(
+            context_clone.clipboard().text(),
+            Ok(Some("Hello, World!".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_emit_cursor_requests() {
+        let (_, mut event_receiver, context, _context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        let uuid = window.id();
+        let cursor =
+            CustomCursor::new(RgbaImage::new(1, 1, vec![255; 4]).unwrap(), (0, 0)).unwrap();
+        while event_receiver.next_event().is_some() {}
+
+        window.set_cursor_visible(false);
+        window.set_cursor_grab(CursorGrabMode::Locked);
+        window.set_cursor_icon(CursorIcon::Crosshair);
+        window.set_custom_cursor(cursor.clone());
+        window.set_cursor_position((10.0, 20.0));
+
+        let mut context_events = Vec::new();
+        while let Some(event) = event_receiver.next_event() {
+            if let Some(context_event) = event.downcast_ref::<WindowContextEvent>() {
+                context_events.push(context_event.to_owned());
+            }
+        }
+        assert_eq!(
+            context_events,
+            vec![
+                WindowContextEvent::WindowCursorVisibilityRequested(uuid, false),
+                WindowContextEvent::WindowCursorGrabRequested(uuid, CursorGrabMode::Locked),
+                WindowContextEvent::WindowCursorIconRequested(uuid, CursorIcon::Crosshair),
+                WindowContextEvent::WindowCustomCursorRequested(uuid, cursor),
+                WindowContextEvent::WindowCursorMoveRequested(uuid, 10.0, 20.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_track_scale_factor() {
+        let (_, _event_receiver, context, context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        assert_eq!(window.scale_factor(), 1.0);
+
+        context_event_sender
+            .send_event(WindowContextEvent::WindowScaleFactorChanged(
+                window.id(),
+                2.0,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            window.scale_factor(),
+            2.0,
+            "The scale factor was not updated"
+        );
+    }
+
+    #[test]
+    fn should_track_window_state() {
+        let (_, _event_receiver, context, context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        let uuid = window.id();
+        assert!(!window.is_focused());
+        assert!(!window.is_minimized());
+        assert!(!window.is_maximized());
+        assert!(!window.is_occluded());
+
+        for event in [
+            WindowContextEvent::WindowFocused(uuid, true),
+            WindowContextEvent::WindowMinimized(uuid, true),
+            WindowContextEvent::WindowMaximized(uuid, true),
+            WindowContextEvent::WindowOccluded(uuid, true),
+        ] {
+            context_event_sender.send_event(event).unwrap();
+        }
+
+        assert!(window.is_focused(), "The window is not focused");
+        assert!(window.is_minimized(), "The window is not minimized");
+        assert!(window.is_maximized(), "The window is not maximized");
+        assert!(window.is_occluded(), "The window is not occluded");
+    }
+
+    #[test]
+    fn should_track_window_position() {
+        let (_, _event_receiver, context, context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        assert_eq!(
+            window.position(),
+            None,
+            "The position should not be known yet"
+        );
+
+        context_event_sender
+            .send_event(WindowContextEvent::WindowMoved(window.id(), 10, 20))
+            .unwrap();
+
+        assert_eq!(
+            window.position(),
+            Some((10, 20)),
+            "The window was not moved"
+        );
+    }
+
+    #[test]
+    fn should_emit_fullscreen_mode_requests() {
+        let (_, mut event_receiver, context, _context_event_sender) = test_init();
+        let window = context.create_window(WindowSettings::default());
+        let video_mode = VideoMode {
+            size: (1920, 1080),
+            bit_depth: 32,
+            refresh_rate_millihertz: 60000,
+        };
+
+        window.set_fullscreen_mode(Some(FullscreenMode::Exclusive(video_mode)));
+
+        while let Some(event) = event_receiver.next_event() {
+            if let Some(WindowContextEvent::WindowFullscreenModeRequested(uuid, fullscreen_mode)) =
+                event.downcast_ref::<WindowContextEvent>()
+            {
+                assert_eq!(*uuid, window.id(), "Event is for the wrong window uuid");
+                assert_eq!(
+                    *fullscreen_mode,
+                    Some(FullscreenMode::Exclusive(video_mode)),
+                    "The fullscreen-mode is not correct"
+                );
+                assert_eq!(
+                    window.fullscreen_mode(),
+                    None,
+                    "The fullscreen-mode should not change until the backend applies it"
+                );
+                return;
+            }
+        }
+
+        panic!("The fullscreen-mode request event was not emitted.");
+    }
+
+    #[test]
+    fn should_track_fullscreen_mode() {
+        let (_, _event_receiver, context, context_event_sender) = test_init();
+        let window = context.create_window(
+            WindowSettings::default().with_fullscreen_mode(FullscreenMode::Borderless),
+        );
+        assert_eq!(window.fullscreen_mode(), Some(FullscreenMode::Borderless));
+
+        context_event_sender
+            .send_event(WindowContextEvent::WindowFullscreenModeChanged(
+                window.id(),
+                None,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            window.fullscreen_mode(),
+            None,
+            "The window is still fullscreen"
+        );
+    }
+
+    #[test]
+    fn should_provide_monitors() {
+        let (_, _event_receiver, context, context_event_sender) = test_init();
+        let monitors = vec![
+            test_monitor(MonitorId(0), false),
+            test_monitor(MonitorId(1), true),
+        ];
+        assert!(context.monitors().is_empty());
+        assert_eq!(context.primary_monitor(), None);
+
+        context_event_sender
+            .send_event(WindowContextEvent::MonitorsChanged(monitors.clone()))
+            .unwrap();
+
+        assert_eq!(context.monitors(), monitors);
+        assert_eq!(context.primary_monitor(), Some(monitors[1].clone()));
+    }
+
+    fn test_monitor(id: MonitorId, is_primary: bool) -> Monitor {
+        Monitor {
+            id,
+            name: Some(format!("Test Monitor {}", id.0)),
+            position: (1920 * id.0 as i32, 0),
+            size: (1920, 1080),
+            scale_factor: 1.0,
+            refresh_rate_millihertz: Some(60000),
+            video_modes: vec![VideoMode {
+                size: (1920, 1080),
+                bit_depth: 32,
+                refresh_rate_millihertz: 60000,
+            }],
+            is_primary,
+        }
     }
 
     #[test]
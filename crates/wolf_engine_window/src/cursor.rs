@@ -0,0 +1,102 @@
+use crate::{RgbaImage, WindowError};
+
+/// Controls whether the cursor is confined to, or locked in a Window.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum CursorGrabMode {
+    /// The cursor can move freely.
+    #[default]
+    None,
+    /// The cursor can't leave the window.
+    Confined,
+    /// The cursor is locked in place, but mouse motion is still reported.
+    ///
+    /// This is useful for first-person camera controls.
+    Locked,
+}
+
+/// A standard cursor icon provided by the system.
+///
+/// The icons are based on the CSS cursor names.  Icons which aren't available on the current
+/// platform fall back to the closest match, or the default icon.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum CursorIcon {
+    /// The platform's default cursor, usually an arrow.
+    #[default]
+    Default,
+    ContextMenu,
+    Help,
+    /// A pointing hand, usually used for links.
+    Pointer,
+    /// The program is busy, but can still be interacted with.
+    Progress,
+    /// The program is busy, and can't be interacted with.
+    Wait,
+    Cell,
+    Crosshair,
+    /// A text-selection caret.
+    Text,
+    VerticalText,
+    Alias,
+    Copy,
+    Move,
+    NoDrop,
+    NotAllowed,
+    Grab,
+    Grabbing,
+    EResize,
+    NResize,
+    NeResize,
+    NwResize,
+    SResize,
+    SeResize,
+    SwResize,
+    WResize,
+    EwResize,
+    NsResize,
+    NeswResize,
+    NwseResize,
+    ColResize,
+    RowResize,
+    AllScroll,
+    ZoomIn,
+    ZoomOut,
+    DndAsk,
+    AllResize,
+}
+
+/// A cursor made from a custom image.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CustomCursor {
+    image: RgbaImage,
+    hotspot: (u32, u32),
+}
+
+impl CustomCursor {
+    /// Create a new cursor from an image.
+    ///
+    /// The hotspot is the pixel which points at things, measured from the top-left corner of the
+    /// image.  Returns an error if the hotspot is outside of the image.
+    pub fn new(image: RgbaImage, hotspot: (u32, u32)) -> Result<Self, WindowError> {
+        if hotspot.0 >= image.width() || hotspot.1 >= image.height() {
+            return Err(WindowError::InvalidImageError(format!(
+                "The hotspot ({}, {}) is outside of the {}x{} image",
+                hotspot.0,
+                hotspot.1,
+                image.width(),
+                image.height()
+            )));
+        }
+        Ok(Self { image, hotspot })
+    }
+
+    /// Get the cursor's image.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// Get the cursor's hotspot.
+    pub fn hotspot(&self) -> (u32, u32) {
+        self.hotspot
+    }
+}
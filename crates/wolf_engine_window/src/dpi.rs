@@ -0,0 +1,144 @@
+//! Provides types for working with physical, and logical sizes on HiDPI displays.
+//!
+//! Physical pixels are the actual pixels on the display.  Logical pixels are scaled by the
+//! window's scale factor, so things sized in logical pixels appear the same size on any display.
+//! For example, a 100x100 logical size is 200x200 physical pixels with a scale factor of 2.0.
+
+/// A size in physical pixels.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PhysicalSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PhysicalSize {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Convert to a logical size using the given scale factor.
+    pub fn to_logical(self, scale_factor: f64) -> LogicalSize {
+        LogicalSize::new(
+            self.width as f64 / scale_factor,
+            self.height as f64 / scale_factor,
+        )
+    }
+}
+
+impl From<(u32, u32)> for PhysicalSize {
+    fn from((width, height): (u32, u32)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+impl From<PhysicalSize> for (u32, u32) {
+    fn from(size: PhysicalSize) -> Self {
+        (size.width, size.height)
+    }
+}
+
+/// A size in logical pixels.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct LogicalSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl LogicalSize {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+
+    /// Convert to a physical size using the given scale factor, rounded to the nearest pixel.
+    pub fn to_physical(self, scale_factor: f64) -> PhysicalSize {
+        PhysicalSize::new(
+            (self.width * scale_factor).round() as u32,
+            (self.height * scale_factor).round() as u32,
+        )
+    }
+}
+
+impl From<(f64, f64)> for LogicalSize {
+    fn from((width, height): (f64, f64)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+/// A size in either physical, or logical pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Size {
+    Physical(PhysicalSize),
+    Logical(LogicalSize),
+}
+
+impl Size {
+    /// Convert to a physical size using the given scale factor.
+    pub fn to_physical(self, scale_factor: f64) -> PhysicalSize {
+        match self {
+            Self::Physical(size) => size,
+            Self::Logical(size) => size.to_physical(scale_factor),
+        }
+    }
+
+    /// Convert to a logical size using the given scale factor.
+    pub fn to_logical(self, scale_factor: f64) -> LogicalSize {
+        match self {
+            Self::Physical(size) => size.to_logical(scale_factor),
+            Self::Logical(size) => size,
+        }
+    }
+}
+
+impl From<PhysicalSize> for Size {
+    fn from(size: PhysicalSize) -> Self {
+        Self::Physical(size)
+    }
+}
+
+impl From<LogicalSize> for Size {
+    fn from(size: LogicalSize) -> Self {
+        Self::Logical(size)
+    }
+}
+
+/// Bare `(u32, u32)` sizes are treated as physical sizes.
+impl From<(u32, u32)> for Size {
+    fn from(size: (u32, u32)) -> Self {
+        Self::Physical(size.into())
+    }
+}
+
+#[cfg(test)]
+mod dpi_tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_between_physical_and_logical_sizes() {
+        let physical_size = PhysicalSize::new(200, 100);
+
+        let logical_size = physical_size.to_logical(2.0);
+
+        assert_eq!(logical_size, LogicalSize::new(100.0, 50.0));
+        assert_eq!(logical_size.to_physical(2.0), physical_size);
+    }
+
+    #[test]
+    fn should_round_to_the_nearest_physical_pixel() {
+        assert_eq!(
+            LogicalSize::new(100.0, 50.0).to_physical(1.25),
+            PhysicalSize::new(125, 63)
+        );
+    }
+
+    #[test]
+    fn should_only_scale_logical_sizes() {
+        assert_eq!(
+            Size::from((100, 100)).to_physical(2.0),
+            PhysicalSize::new(100, 100)
+        );
+        assert_eq!(
+            Size::from(LogicalSize::new(100.0, 100.0)).to_physical(2.0),
+            PhysicalSize::new(200, 200)
+        );
+    }
+}
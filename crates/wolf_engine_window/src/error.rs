@@ -7,4 +7,10 @@ pub enum WindowError {
     /// Indicates the operation is not supposrted by either the window system, or the current OS.
     #[error("Operation is unsupported by either the window system, or the current OS.")]
     UnsupportedError(String),
+    /// Indicates image data is invalid, such as when it doesn't match the size of the image.
+    #[error("The image data is invalid: {0}")]
+    InvalidImageError(String),
+    /// Indicates the clipboard could not be accessed.
+    #[error("Failed to access the clipboard: {0}")]
+    ClipboardError(String),
 }
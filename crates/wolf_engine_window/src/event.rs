@@ -1,8 +1,10 @@
 //! Provides an event system for the window crate.
 
+use std::path::PathBuf;
+
 use uuid::Uuid;
 use wolf_engine_events::dynamic::Event;
-use wolf_engine_input::Input;
+use wolf_engine_input::{device::DeviceId, Input};
 
 use crate::error::WindowError;
 
@@ -13,11 +15,84 @@ pub enum WindowEvent {
     Started,
     Exited,
     EventsCleared,
-    Input(Option<Uuid>, Input),
+    /// An input was received.
+    ///
+    /// Includes the window which received the input, and the device which produced it, if they
+    /// are known.
+    Input(Option<Uuid>, Option<DeviceId>, Input),
+    /// An input device was connected.
+    DeviceConnected(DeviceId),
+    /// An input device was disconnected.
+    DeviceDisconnected(DeviceId),
     WindowReady(Uuid, Result<(), WindowError>),
     WindowRedrawRequested(Uuid),
     WindowClosed(Uuid),
+    /// A window was resized, and includes its new size in physical pixels.
     WindowResized(Uuid, u32, u32),
+    /// A window's scale factor has changed, such as when it's moved to a different display.
+    ///
+    /// The window will usually be resized to match.
+    ScaleFactorChanged(Uuid, f64),
+    /// A window was moved, and includes the new position of its top-left corner on the desktop.
+    Moved(Uuid, i32, i32),
+    /// A window gained, or lost keyboard focus.
+    Focused(Uuid, bool),
+    /// A window was minimized.
+    Minimized(Uuid),
+    /// A window was maximized.
+    Maximized(Uuid),
+    /// A window was restored from being minimized, or maximized.
+    Restored(Uuid),
+    /// A window became fully hidden by other windows, or visible again.
+    ///
+    /// Occluded windows don't need to be redrawn.
+    ///
+    /// # Portability
+    ///
+    /// Not all platforms report occlusion.
+    Occluded(Uuid, bool),
+    /// A file is being dragged over a window.
+    ///
+    /// When several files are dragged at once, one event is emitted for each file.
+    FileHovered(Uuid, PathBuf),
+    /// A file was dropped on a window.
+    ///
+    /// When several files are dropped at once, one event is emitted for each file.
+    FileDropped(Uuid, PathBuf),
+    /// The files being dragged over a window left it, or the drag was cancelled.
+    FileHoverCancelled(Uuid),
+    /// A request made through a [`Window`](crate::Window) was refused by the window system.
+    ///
+    /// For example, locking the cursor fails with [`WindowError::UnsupportedError`] on platforms
+    /// which can't lock it.
+    RequestFailed(Uuid, WindowError),
 }
 
 impl Event for WindowEvent {}
+
+/// Converts to an [`WindowEvent::Input`], which isn't associated with any window, or device.
+///
+/// This allows simulated inputs to be injected into an event queue.
+///
+/// ```
+/// # use wolf_engine_events::{dynamic::AnyEvent, mpsc, EventReceiver};
+/// # use wolf_engine_input::{keyboard::KeyCode, simulator::InputSimulator};
+/// # use wolf_engine_window::WindowEvent;
+/// #
+/// let (event_sender, mut event_receiver) = mpsc::event_queue::<AnyEvent>();
+/// let mut simulator = InputSimulator::default();
+/// simulator.type_text("hi");
+///
+/// simulator.send_all::<WindowEvent, _>(&event_sender).unwrap();
+///
+/// let event = event_receiver.next_event().unwrap();
+/// assert!(matches!(
+///     event.downcast_ref::<WindowEvent>(),
+///     Some(WindowEvent::Input(None, None, _)),
+/// ));
+/// ```
+impl From<Input> for WindowEvent {
+    fn from(input: Input) -> Self {
+        Self::Input(None, None, input)
+    }
+}
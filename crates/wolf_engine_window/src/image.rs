@@ -0,0 +1,45 @@
+use crate::WindowError;
+
+/// An image made of 8-bit RGBA pixels, used for window icons, and cursors.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RgbaImage {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Create a new image from RGBA pixel data.
+    ///
+    /// The pixels are stored row-by-row, starting from the top-left corner, with 4 bytes per
+    /// pixel.  Returns an error if the length of the data doesn't match the size of the image.
+    pub fn new(width: u32, height: u32, rgba: Vec<u8>) -> Result<Self, WindowError> {
+        let expected_length = width as usize * height as usize * 4;
+        if width == 0 || height == 0 || rgba.len() != expected_length {
+            return Err(WindowError::InvalidImageError(format!(
+                "Expected {expected_length} bytes of RGBA data for a {width}x{height} image, got {}",
+                rgba.len()
+            )));
+        }
+        Ok(Self {
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// Get the width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the RGBA pixel data.
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+}
@@ -7,9 +7,18 @@
 
 pub mod backend;
 mod context;
+pub mod dpi;
 pub use context::WindowContext;
 mod window;
 pub use window::*;
+mod monitor;
+pub use monitor::*;
+mod image;
+pub use image::*;
+mod cursor;
+pub use cursor::*;
+mod clipboard;
+pub use clipboard::*;
 
 mod event;
 pub use event::*;
@@ -25,24 +34,76 @@ pub mod raw_window_handle;
 #[cfg(test)]
 mod window_system_tests {
     use super::*;
+    use crate::dpi::{LogicalSize, Size};
 
     #[test]
     fn should_set_settings_values() {
         let window_settings = WindowSettings::default()
             .with_title("Custom Test Title")
-            .with_size((123, 123))
+            .with_size(LogicalSize::new(123.0, 123.0))
             .with_resizable(false)
             .with_visible(false)
-            .with_fullscreen_mode(FullscreenMode::Borderless);
+            .with_fullscreen_mode(FullscreenMode::Borderless)
+            .with_monitor(MonitorId(1));
 
         assert_eq!(window_settings.title, "Custom Test Title");
-        assert_eq!(window_settings.size, (123, 123));
-        assert_eq!(window_settings.is_resizable, false);
-        assert_eq!(window_settings.is_visible, false);
+        assert_eq!(
+            window_settings.size,
+            Size::Logical(LogicalSize::new(123.0, 123.0))
+        );
+        assert!(!window_settings.is_resizable);
+        assert!(!window_settings.is_visible);
         assert_eq!(
             window_settings.fullscreen_mode,
             Some(FullscreenMode::Borderless)
         );
+        assert_eq!(window_settings.monitor, Some(MonitorId(1)));
+    }
+
+    #[test]
+    fn should_set_extended_settings_values() {
+        let icon = RgbaImage::new(2, 2, vec![255; 16]).unwrap();
+        let window_settings = WindowSettings::default()
+            .with_position(WindowPosition::At(10, 20))
+            .with_min_size((100, 100))
+            .with_max_size((1000, 1000))
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_window_level(WindowLevel::AlwaysOnTop)
+            .with_icon(icon.clone());
+
+        assert_eq!(window_settings.position, Some(WindowPosition::At(10, 20)));
+        assert_eq!(window_settings.min_size, Some((100, 100).into()));
+        assert_eq!(window_settings.max_size, Some((1000, 1000).into()));
+        assert!(!window_settings.has_decorations);
+        assert!(window_settings.is_transparent);
+        assert_eq!(window_settings.window_level, WindowLevel::AlwaysOnTop);
+        assert_eq!(window_settings.icon, Some(icon));
+    }
+
+    #[test]
+    fn should_have_decorations_by_default() {
+        let window_settings = WindowSettings::default();
+
+        assert!(window_settings.has_decorations);
+        assert!(!window_settings.is_transparent);
+        assert_eq!(window_settings.window_level, WindowLevel::Normal);
+    }
+
+    #[test]
+    fn should_reject_hotspots_outside_of_the_cursor() {
+        let image = RgbaImage::new(2, 2, vec![255; 16]).unwrap();
+
+        assert!(CustomCursor::new(image.clone(), (1, 1)).is_ok());
+        assert!(CustomCursor::new(image.clone(), (2, 0)).is_err());
+        assert!(CustomCursor::new(image, (0, 2)).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_image_data() {
+        assert!(RgbaImage::new(2, 2, vec![255; 16]).is_ok());
+        assert!(RgbaImage::new(2, 2, vec![255; 15]).is_err());
+        assert!(RgbaImage::new(0, 0, Vec::new()).is_err());
     }
 
     #[test]
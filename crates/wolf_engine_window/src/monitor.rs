@@ -0,0 +1,39 @@
+/// Identifies a monitor.
+///
+/// Ids are assigned by the backend, and stay the same for as long as it is running.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct MonitorId(pub u64);
+
+/// A monitor connected to the system.
+///
+/// Monitors are provided by the [`WindowContext`](crate::WindowContext).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Monitor {
+    pub id: MonitorId,
+    /// The human-readable name of the monitor, if it's known.
+    pub name: Option<String>,
+    /// The position of the monitor's top-left corner on the desktop, in pixels.
+    pub position: (i32, i32),
+    /// The resolution of the monitor in pixels.
+    pub size: (u32, u32),
+    /// The ratio of physical pixels to logical pixels.
+    pub scale_factor: f64,
+    /// The current refresh rate in millihertz, if it's known.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// The video modes supported by the monitor, for use with
+    /// [`FullscreenMode::Exclusive`](crate::FullscreenMode::Exclusive).
+    pub video_modes: Vec<VideoMode>,
+    /// Indicates if this is the system's primary monitor.
+    pub is_primary: bool,
+}
+
+/// A display resolution, bit depth, and refresh rate supported by a monitor.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct VideoMode {
+    /// The resolution in pixels.
+    pub size: (u32, u32),
+    /// The number of bits per pixel.
+    pub bit_depth: u16,
+    /// The refresh rate in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
@@ -3,22 +3,64 @@ use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 use wolf_engine_events::EventSender;
 
-use crate::{backend::event::WindowContextEvent, raw_window_handle::WindowHandle, WindowContext};
+use crate::{
+    backend::event::WindowContextEvent,
+    dpi::{PhysicalSize, Size},
+    raw_window_handle::WindowHandle,
+    CursorGrabMode, CursorIcon, CustomCursor, MonitorId, RgbaImage, VideoMode, WindowContext,
+};
 
 /// The fullscreen-mode for a Window.
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum FullscreenMode {
+    /// The window covers the whole monitor, without changing its video mode.
     Borderless,
+    /// The window takes exclusive control of the monitor, and switches it to the given video
+    /// mode.
+    ///
+    /// # Portability
+    ///
+    /// If the video mode isn't supported by the monitor, the window will not be made fullscreen.
+    Exclusive(VideoMode),
+}
+
+/// The initial position of a Window.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum WindowPosition {
+    /// The window's top-left corner is placed at the given position on the desktop.
+    At(i32, i32),
+    /// The window is centered on its monitor.
+    Centered,
+}
+
+/// Controls whether a Window is kept above, or below other windows.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum WindowLevel {
+    /// The window is ordered normally.
+    #[default]
+    Normal,
+    /// The window is kept above normal windows.
+    AlwaysOnTop,
+    /// The window is kept below normal windows.
+    AlwaysOnBottom,
 }
 
 /// The settings used by the [`WindowContext`](crate::WindowContext) when creating the window.
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct WindowSettings {
     pub title: String,
-    pub size: (u32, u32),
+    pub size: Size,
     pub is_resizable: bool,
     pub is_visible: bool,
     pub fullscreen_mode: Option<FullscreenMode>,
+    pub monitor: Option<MonitorId>,
+    pub position: Option<WindowPosition>,
+    pub min_size: Option<Size>,
+    pub max_size: Option<Size>,
+    pub has_decorations: bool,
+    pub is_transparent: bool,
+    pub window_level: WindowLevel,
+    pub icon: Option<RgbaImage>,
 }
 
 impl WindowSettings {
@@ -28,9 +70,13 @@ impl WindowSettings {
         self
     }
 
-    /// Set the window's size.
-    pub fn with_size(mut self, size: (u32, u32)) -> Self {
-        self.size = size;
+    /// Set the window's size, in either physical, or logical pixels.
+    ///
+    /// Bare `(u32, u32)` sizes are in physical pixels.  Use a
+    /// [`LogicalSize`](crate::dpi::LogicalSize) to keep the window the same size on HiDPI
+    /// displays.
+    pub fn with_size<S: Into<Size>>(mut self, size: S) -> Self {
+        self.size = size.into();
         self
     }
 
@@ -50,16 +96,82 @@ impl WindowSettings {
         self.fullscreen_mode = Some(fullscreen_mode);
         self
     }
+
+    /// Set the monitor the window should be opened on.
+    ///
+    /// Fullscreen windows will cover this monitor.  If it's not set, or the monitor isn't
+    /// connected, the primary monitor is used.
+    pub fn with_monitor(mut self, monitor: MonitorId) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Set the window's initial position.
+    ///
+    /// If it's not set, the window system will choose a position.
+    pub fn with_position(mut self, position: WindowPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Set the smallest size the window can be resized to.
+    pub fn with_min_size<S: Into<Size>>(mut self, min_size: S) -> Self {
+        self.min_size = Some(min_size.into());
+        self
+    }
+
+    /// Set the largest size the window can be resized to.
+    pub fn with_max_size<S: Into<Size>>(mut self, max_size: S) -> Self {
+        self.max_size = Some(max_size.into());
+        self
+    }
+
+    /// Set whether or not the window should have a title bar, and borders.
+    pub fn with_decorations(mut self, has_decorations: bool) -> Self {
+        self.has_decorations = has_decorations;
+        self
+    }
+
+    /// Set whether or not the window's background should be transparent.
+    ///
+    /// # Portability
+    ///
+    /// Not all platforms support transparent windows, and the renderer must also draw with
+    /// transparency for it to have any effect.
+    pub fn with_transparent(mut self, is_transparent: bool) -> Self {
+        self.is_transparent = is_transparent;
+        self
+    }
+
+    /// Set whether the window should be kept above, or below other windows.
+    pub fn with_window_level(mut self, window_level: WindowLevel) -> Self {
+        self.window_level = window_level;
+        self
+    }
+
+    /// Set the window's icon.
+    pub fn with_icon(mut self, icon: RgbaImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
 }
 
 impl Default for WindowSettings {
     fn default() -> Self {
         Self {
             title: "Untitled".to_string(),
-            size: (1280, 720),
+            size: (1280, 720).into(),
             is_resizable: true,
             is_visible: true,
             fullscreen_mode: None,
+            monitor: None,
+            position: None,
+            min_size: None,
+            max_size: None,
+            has_decorations: true,
+            is_transparent: false,
+            window_level: WindowLevel::Normal,
+            icon: None,
         }
     }
 }
@@ -81,43 +193,195 @@ impl Window {
         self.state.uuid
     }
 
-    /// Get the current size of the window.
-    pub fn size(&self) -> (u32, u32) {
+    /// Get the current size of the window in physical pixels.
+    ///
+    /// Use [`PhysicalSize::to_logical()`] with the [`Window::scale_factor()`] to get the size in
+    /// logical pixels.
+    pub fn size(&self) -> PhysicalSize {
         self.state.size()
     }
 
+    /// Get the ratio of physical pixels to logical pixels for the window's current display.
+    ///
+    /// The scale factor is 1.0 until the window system reports it.
+    pub fn scale_factor(&self) -> f64 {
+        self.state.scale_factor()
+    }
+
+    /// Request a new size for the window, in either physical, or logical pixels.
+    ///
+    /// [`Window::size()`] will not reflect the new size until the window system has resized the
+    /// window.
+    pub fn set_size<S: Into<Size>>(&self, size: S) {
+        self.send_event(WindowContextEvent::WindowResizeRequested(
+            self.id(),
+            size.into(),
+        ));
+    }
+
+    /// Get the position of the window's top-left corner on the desktop, if it's known.
+    pub fn position(&self) -> Option<(i32, i32)> {
+        self.state.position()
+    }
+
+    /// Returns true if the window has keyboard focus.
+    pub fn is_focused(&self) -> bool {
+        *self.state.is_focused.read().unwrap()
+    }
+
+    /// Returns true if the window is minimized.
+    pub fn is_minimized(&self) -> bool {
+        *self.state.is_minimized.read().unwrap()
+    }
+
+    /// Returns true if the window is maximized.
+    pub fn is_maximized(&self) -> bool {
+        *self.state.is_maximized.read().unwrap()
+    }
+
+    /// Returns true if the window is fully hidden by other windows.
+    ///
+    /// # Portability
+    ///
+    /// Not all platforms report occlusion, so this may always be false.
+    pub fn is_occluded(&self) -> bool {
+        *self.state.is_occluded.read().unwrap()
+    }
+
+    /// Request a new position for the window's top-left corner on the desktop.
+    pub fn set_position(&self, position: (i32, i32)) {
+        self.send_event(WindowContextEvent::WindowMoveRequested(
+            self.id(),
+            position.0,
+            position.1,
+        ));
+    }
+
+    /// Show, or hide the window.
+    pub fn set_visible(&self, is_visible: bool) {
+        self.send_event(WindowContextEvent::WindowVisibilityRequested(
+            self.id(),
+            is_visible,
+        ));
+    }
+
+    /// Set the smallest size the window can be resized to, or `None` for no limit.
+    pub fn set_min_size(&self, min_size: Option<Size>) {
+        self.send_event(WindowContextEvent::WindowMinSizeRequested(
+            self.id(),
+            min_size,
+        ));
+    }
+
+    /// Set the largest size the window can be resized to, or `None` for no limit.
+    pub fn set_max_size(&self, max_size: Option<Size>) {
+        self.send_event(WindowContextEvent::WindowMaxSizeRequested(
+            self.id(),
+            max_size,
+        ));
+    }
+
+    /// Minimize the window.
+    pub fn minimize(&self) {
+        self.send_event(WindowContextEvent::WindowMinimizeRequested(self.id()));
+    }
+
+    /// Maximize the window.
+    pub fn maximize(&self) {
+        self.send_event(WindowContextEvent::WindowMaximizeRequested(self.id()));
+    }
+
+    /// Restore the window from being minimized, or maximized.
+    pub fn restore(&self) {
+        self.send_event(WindowContextEvent::WindowRestoreRequested(self.id()));
+    }
+
+    /// Show, or hide the cursor while it's over the window.
+    pub fn set_cursor_visible(&self, is_visible: bool) {
+        self.send_event(WindowContextEvent::WindowCursorVisibilityRequested(
+            self.id(),
+            is_visible,
+        ));
+    }
+
+    /// Confine, or lock the cursor to the window.
+    ///
+    /// If the platform doesn't support the grab mode, a
+    /// [`WindowEvent::RequestFailed`](crate::WindowEvent::RequestFailed) event is emitted.
+    pub fn set_cursor_grab(&self, grab_mode: CursorGrabMode) {
+        self.send_event(WindowContextEvent::WindowCursorGrabRequested(
+            self.id(),
+            grab_mode,
+        ));
+    }
+
+    /// Set the cursor's icon while it's over the window.
+    pub fn set_cursor_icon(&self, icon: CursorIcon) {
+        self.send_event(WindowContextEvent::WindowCursorIconRequested(
+            self.id(),
+            icon,
+        ));
+    }
+
+    /// Use a custom image for the cursor while it's over the window.
+    pub fn set_custom_cursor(&self, cursor: CustomCursor) {
+        self.send_event(WindowContextEvent::WindowCustomCursorRequested(
+            self.id(),
+            cursor,
+        ));
+    }
+
+    /// Move the cursor to a position in the window, in physical pixels from its top-left corner.
+    ///
+    /// If the platform doesn't support moving the cursor, a
+    /// [`WindowEvent::RequestFailed`](crate::WindowEvent::RequestFailed) event is emitted.
+    pub fn set_cursor_position(&self, position: (f32, f32)) {
+        self.send_event(WindowContextEvent::WindowCursorMoveRequested(
+            self.id(),
+            position.0,
+            position.1,
+        ));
+    }
+
     /// Set the title of the window.
     pub fn set_title(&self, new_title: &str) {
-        self.context
-            .event_sender
-            .send_event(Box::new(WindowContextEvent::WindowRenameRequested(
-                self.id(),
-                new_title.into(),
-            )))
-            .unwrap();
+        self.send_event(WindowContextEvent::WindowRenameRequested(
+            self.id(),
+            new_title.into(),
+        ));
     }
 
     /// Get the current fullscreen-mode, if the window is in full-screen.
     pub fn fullscreen_mode(&self) -> Option<FullscreenMode> {
-        None
+        self.state.fullscreen_mode()
     }
 
-    /// Set the fullscreen-mode.
-    pub fn set_fullscreen_mode(&self, _fullscreen_mode: Option<FullscreenMode>) {}
+    /// Set the fullscreen-mode, or leave fullscreen if the mode is `None`.
+    ///
+    /// The change is applied by the window system, so [`Window::fullscreen_mode()`] will not
+    /// reflect it until the window system has handled the request.
+    pub fn set_fullscreen_mode(&self, fullscreen_mode: Option<FullscreenMode>) {
+        self.send_event(WindowContextEvent::WindowFullscreenModeRequested(
+            self.id(),
+            fullscreen_mode,
+        ));
+    }
 
     /// Request a redraw of the window.
     pub fn redraw(&self) {
-        self.context
-            .event_sender
-            .send_event(Box::new(WindowContextEvent::WindowRedrawRequested(
-                self.id(),
-            )))
-            .unwrap();
+        self.send_event(WindowContextEvent::WindowRedrawRequested(self.id()));
     }
 
     pub fn handle(&self) -> Option<WindowHandle> {
         self.state.handle()
     }
+
+    fn send_event(&self, event: WindowContextEvent) {
+        self.context
+            .event_sender
+            .send_event(Box::new(event))
+            .unwrap();
+    }
 }
 
 impl PartialEq for Window {
@@ -145,23 +409,62 @@ pub(crate) struct WindowState {
     pub uuid: Uuid,
     pub settings: RwLock<WindowSettings>,
     pub handle: RwLock<Option<WindowHandle>>,
+    pub position: RwLock<Option<(i32, i32)>>,
+    pub size: RwLock<PhysicalSize>,
+    pub scale_factor: RwLock<f64>,
+    pub is_focused: RwLock<bool>,
+    pub is_minimized: RwLock<bool>,
+    pub is_maximized: RwLock<bool>,
+    pub is_occluded: RwLock<bool>,
 }
 
 impl WindowState {
     pub fn new(uuid: Uuid, settings: WindowSettings) -> Self {
+        let size = settings.size.to_physical(1.0);
         Self {
             uuid,
             settings: RwLock::new(settings),
             handle: RwLock::new(None),
+            position: RwLock::new(None),
+            size: RwLock::new(size),
+            scale_factor: RwLock::new(1.0),
+            is_focused: RwLock::new(false),
+            is_minimized: RwLock::new(false),
+            is_maximized: RwLock::new(false),
+            is_occluded: RwLock::new(false),
         }
     }
 
-    pub fn size(&self) -> (u32, u32) {
-        self.settings.read().unwrap().size
+    pub fn size(&self) -> PhysicalSize {
+        *self.size.read().unwrap()
     }
 
     pub fn resize(&self, width: u32, height: u32) {
-        self.settings.write().unwrap().size = (width, height);
+        *self.size.write().unwrap() = PhysicalSize::new(width, height);
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        *self.scale_factor.read().unwrap()
+    }
+
+    pub fn set_scale_factor(&self, scale_factor: f64) {
+        *self.scale_factor.write().unwrap() = scale_factor;
+    }
+
+    pub fn position(&self) -> Option<(i32, i32)> {
+        *self.position.read().unwrap()
+    }
+
+    pub fn set_position(&self, x: i32, y: i32) {
+        *self.position.write().unwrap() = Some((x, y));
+    }
+
+    pub fn fullscreen_mode(&self) -> Option<FullscreenMode> {
+        self.settings.read().unwrap().fullscreen_mode
+    }
+
+    pub fn set_fullscreen_mode(&self, fullscreen_mode: Option<FullscreenMode>) {
+        self.settings.write().unwrap().fullscreen_mode = fullscreen_mode;
     }
 
     pub fn handle(&self) -> Option<WindowHandle> {
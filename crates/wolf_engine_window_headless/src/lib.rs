@@ -0,0 +1,1174 @@
+//! Provides a headless [`WindowSystem`] backend, for running automated tests without a display.
+//!
+//! Windows created through the [`WindowContext`] are virtual.  They are tracked by the backend,
+//! and emit the same events a real window would, but nothing is ever shown on screen, and they
+//! have no [`WindowHandle`](wolf_engine_window::raw_window_handle::WindowHandle).
+//!
+//! Anything which would normally come from the OS, such as input, resizing, or close requests, is
+//! injected through a [`HeadlessController`] instead.
+//!
+//! Unless a position is set, virtual windows open at the top-left corner of their monitor, or the
+//! desktop if there are no monitors.  Minimizing, and maximizing windows only changes their state,
+//! and doesn't affect their size.  There is no cursor, so cursor requests are accepted, but have
+//! no effect.  The clipboard is a [`MemoryClipboard`], so its contents aren't shared with other
+//! applications.
+//!
+//! # Examples
+//!
+//! ```
+//! # use wolf_engine_events::event_loop::EventLoop;
+//! # use wolf_engine_window::{backend::WindowSystem, WindowEvent, WindowSettings};
+//! #
+//! let window_system = wolf_engine_window_headless::init();
+//! let context = window_system.context();
+//! let controller = window_system.controller();
+//! let mut window = None;
+//! let mut window_size = None;
+//!
+//! window_system.run(|event| {
+//!     if let Some(window_event) = event.downcast_ref::<WindowEvent>() {
+//!         match window_event {
+//!             WindowEvent::Started => {
+//!                 window = Some(context.create_window(WindowSettings::default()));
+//!             }
+//!             WindowEvent::WindowReady(uuid, _) => controller.resize_window(*uuid, 800, 600),
+//!             WindowEvent::WindowResized(_, width, height) => {
+//!                 window_size = Some((*width, *height));
+//!                 context.exit();
+//!             }
+//!             _ => (),
+//!         }
+//!     }
+//! });
+//!
+//! assert_eq!(window_size, Some((800, 600)));
+//! assert_eq!(window.unwrap().size(), (800, 600).into());
+//! ```
+
+use std::{collections::HashMap, path::PathBuf};
+
+use wolf_engine_events::{
+    dynamic::{AnyEvent, Event},
+    mpsc::{self, MpscEventReceiver, MpscEventSender},
+    EventReceiver, EventSender,
+};
+use wolf_engine_input::{device::DeviceId, DeviceInputStates, Input};
+use wolf_engine_window::{
+    backend::{
+        event::{WindowContextEvent, WindowContextEventSender},
+        WindowSystem,
+    },
+    dpi::PhysicalSize,
+    MemoryClipboard, Monitor, Uuid, WindowContext, WindowEvent, WindowPosition, WindowSettings,
+};
+
+/// Initialize the headless window system.
+pub fn init() -> HeadlessBackend {
+    let (event_sender, event_receiver) = mpsc::event_queue();
+    let (window_context, window_context_event_sender) =
+        WindowContext::with_clipboard(event_sender.clone(), MemoryClipboard::default());
+    HeadlessBackend {
+        event_sender,
+        event_receiver,
+        window_context,
+        window_context_event_sender,
+        input_states: DeviceInputStates::default(),
+        monitors: Vec::new(),
+    }
+}
+
+/// A [`WindowSystem`] with virtual windows, which doesn't need a display.
+///
+/// The event-loop runs as fast as it can, until [`WindowContext::exit()`] is called.
+pub struct HeadlessBackend {
+    event_sender: MpscEventSender<AnyEvent>,
+    event_receiver: MpscEventReceiver<AnyEvent>,
+    window_context: WindowContext,
+    window_context_event_sender: WindowContextEventSender,
+    input_states: DeviceInputStates,
+    monitors: Vec<Monitor>,
+}
+
+impl HeadlessBackend {
+    /// Set whether or not the primary touch should be emulated as mouse input.
+    ///
+    /// See [`DeviceInputStates::with_mouse_emulation()`] for details.
+    pub fn with_mouse_emulation(mut self, is_emulating_mouse: bool) -> Self {
+        self.input_states = self.input_states.with_mouse_emulation(is_emulating_mouse);
+        self
+    }
+
+    /// Set the fake monitors reported by the window system.
+    ///
+    /// There are no monitors by default.
+    pub fn with_monitors(mut self, monitors: Vec<Monitor>) -> Self {
+        self.monitors = monitors;
+        self
+    }
+
+    /// Get a [`HeadlessController`] which can inject events into the event-loop.
+    pub fn controller(&self) -> HeadlessController {
+        HeadlessController {
+            event_sender: self.event_sender.clone(),
+        }
+    }
+}
+
+impl WindowSystem for HeadlessBackend {
+    fn context(&self) -> WindowContext {
+        self.window_context.clone()
+    }
+}
+
+impl wolf_engine_events::event_loop::EventLoop<AnyEvent> for HeadlessBackend {
+    fn event_sender(&self) -> MpscEventSender<AnyEvent> {
+        self.event_sender.clone()
+    }
+
+    fn run<F: FnMut(AnyEvent)>(self, event_handler: F) {
+        let mut headless_app = HeadlessApp {
+            event_handler,
+            _event_sender: self.event_sender,
+            event_receiver: self.event_receiver,
+            window_context_event_sender: self.window_context_event_sender,
+            input_states: self.input_states,
+            windows: HashMap::new(),
+            monitors: Vec::new(),
+            has_exited: false,
+        };
+        headless_app.run(self.monitors);
+    }
+}
+
+/// Injects events into a running [`HeadlessBackend`], in place of the OS.
+///
+/// Injected events are handled in the order they are sent, along with events from the
+/// [`WindowContext`].  Events for windows which don't exist, or have been closed, are ignored.
+#[derive(Clone)]
+pub struct HeadlessController {
+    event_sender: MpscEventSender<AnyEvent>,
+}
+
+impl HeadlessController {
+    /// Inject an input, as if it came from the given window, and device.
+    pub fn send_input(&self, uuid: Option<Uuid>, device_id: Option<DeviceId>, input: Input) {
+        self.send(HeadlessEvent::Input(uuid, device_id, input));
+    }
+
+    /// Resize a window, as if the user had resized it.
+    pub fn resize_window(&self, uuid: Uuid, width: u32, height: u32) {
+        self.send(HeadlessEvent::WindowResized(uuid, width, height));
+    }
+
+    /// Give, or take keyboard focus from a window, as if the user had clicked on it, or away.
+    ///
+    /// Like a real window, this also sends [`Input::FocusGained`], or [`Input::FocusLost`], so
+    /// held keys, and buttons are released when focus is lost.
+    pub fn set_focused(&self, uuid: Uuid, is_focused: bool) {
+        self.send(HeadlessEvent::Focused(uuid, is_focused));
+    }
+
+    /// Hide, or reveal a window, as if it were covered by another window.
+    pub fn set_occluded(&self, uuid: Uuid, is_occluded: bool) {
+        self.send(HeadlessEvent::Occluded(uuid, is_occluded));
+    }
+
+    /// Change a window's scale factor, as if it were moved to a display with a different scale.
+    ///
+    /// The window is resized to keep the same logical size.
+    pub fn set_scale_factor(&self, uuid: Uuid, scale_factor: f64) {
+        self.send(HeadlessEvent::ScaleFactorChanged(uuid, scale_factor));
+    }
+
+    /// Replace the fake monitors reported by the window system, as if monitors were connected,
+    /// or disconnected.
+    pub fn set_monitors(&self, monitors: Vec<Monitor>) {
+        self.send(HeadlessEvent::MonitorsChanged(monitors));
+    }
+
+    /// Drag a file over a window, as if the user were about to drop it.
+    pub fn hover_file<P: Into<PathBuf>>(&self, uuid: Uuid, path: P) {
+        self.send(HeadlessEvent::FileHovered(uuid, path.into()));
+    }
+
+    /// Drop a file on a window, as if the user had dragged it there.
+    pub fn drop_file<P: Into<PathBuf>>(&self, uuid: Uuid, path: P) {
+        self.send(HeadlessEvent::FileDropped(uuid, path.into()));
+    }
+
+    /// Drag the hovered files away from a window, without dropping them.
+    pub fn cancel_file_hover(&self, uuid: Uuid) {
+        self.send(HeadlessEvent::FileHoverCancelled(uuid));
+    }
+
+    /// Request a window to close, as if the user had clicked its close button.
+    ///
+    /// Like a real window, the window isn't closed until the last copy of it is dropped.
+    pub fn request_close(&self, uuid: Uuid) {
+        self.send(HeadlessEvent::WindowCloseRequested(uuid));
+    }
+
+    fn send(&self, event: HeadlessEvent) {
+        let _ = self.event_sender.send_event(Box::new(event));
+    }
+}
+
+/// Events sent by the [`HeadlessController`].
+#[derive(Debug)]
+enum HeadlessEvent {
+    Input(Option<Uuid>, Option<DeviceId>, Input),
+    WindowResized(Uuid, u32, u32),
+    ScaleFactorChanged(Uuid, f64),
+    Focused(Uuid, bool),
+    Occluded(Uuid, bool),
+    FileHovered(Uuid, PathBuf),
+    FileDropped(Uuid, PathBuf),
+    FileHoverCancelled(Uuid),
+    WindowCloseRequested(Uuid),
+    MonitorsChanged(Vec<Monitor>),
+}
+
+impl Event for HeadlessEvent {}
+
+struct HeadlessApp<H: FnMut(AnyEvent)> {
+    event_handler: H,
+    _event_sender: MpscEventSender<AnyEvent>,
+    event_receiver: MpscEventReceiver<AnyEvent>,
+    window_context_event_sender: WindowContextEventSender,
+    input_states: DeviceInputStates,
+    windows: HashMap<Uuid, HeadlessWindow>,
+    monitors: Vec<Monitor>,
+    has_exited: bool,
+}
+
+/// A virtual window.
+struct HeadlessWindow {
+    settings: WindowSettings,
+    scale_factor: f64,
+    is_minimized: bool,
+    is_maximized: bool,
+}
+
+impl<H: FnMut(AnyEvent)> HeadlessApp<H> {
+    fn run(&mut self, monitors: Vec<Monitor>) {
+        self.update_monitors(monitors);
+        (self.event_handler)(Box::new(WindowEvent::Started));
+        while !self.has_exited {
+            self.process_events();
+        }
+    }
+
+    fn process_events(&mut self) {
+        while let Some(event) = self.event_receiver.next_event() {
+            self.handle_event(event);
+            if self.has_exited {
+                return;
+            }
+        }
+        (self.event_handler)(Box::new(WindowEvent::EventsCleared));
+    }
+
+    fn handle_event(&mut self, event: AnyEvent) {
+        if let Some(context_event) = event.downcast_ref::<WindowContextEvent>() {
+            match context_event {
+                WindowContextEvent::Exited => {
+                    self.has_exited = true;
+                    (self.event_handler)(Box::new(WindowEvent::Exited));
+                }
+                WindowContextEvent::WindowCreated(uuid, settings) => {
+                    let scale_factor = self
+                        .window_monitor(settings)
+                        .map_or(1.0, |monitor| monitor.scale_factor);
+                    let size = settings.size.to_physical(scale_factor);
+                    let (x, y) = self.initial_position(settings, size);
+                    self.windows.insert(
+                        *uuid,
+                        HeadlessWindow {
+                            settings: settings.to_owned(),
+                            scale_factor,
+                            is_minimized: false,
+                            is_maximized: false,
+                        },
+                    );
+                    self.window_context_event_sender
+                        .send_event(WindowContextEvent::WindowScaleFactorChanged(
+                            *uuid,
+                            scale_factor,
+                        ))
+                        .unwrap();
+                    self.window_context_event_sender
+                        .send_event(WindowContextEvent::WindowResized(
+                            *uuid,
+                            size.width,
+                            size.height,
+                        ))
+                        .unwrap();
+                    self.move_window(*uuid, x, y);
+                    (self.event_handler)(Box::new(WindowEvent::WindowReady(*uuid, Ok(()))));
+                }
+                WindowContextEvent::WindowResizeRequested(uuid, size) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        let size = size.to_physical(window.scale_factor);
+                        self.resize_window(*uuid, size.width, size.height)
+                    }
+                }
+                WindowContextEvent::WindowMoveRequested(uuid, x, y)
+                    if self.windows.contains_key(uuid) =>
+                {
+                    self.move_window(*uuid, *x, *y);
+                    (self.event_handler)(Box::new(WindowEvent::Moved(*uuid, *x, *y)));
+                }
+                WindowContextEvent::WindowMinimizeRequested(uuid) => {
+                    self.set_window_mode(*uuid, true, false)
+                }
+                WindowContextEvent::WindowMaximizeRequested(uuid) => {
+                    self.set_window_mode(*uuid, false, true)
+                }
+                WindowContextEvent::WindowRestoreRequested(uuid) => {
+                    self.set_window_mode(*uuid, false, false)
+                }
+                WindowContextEvent::WindowVisibilityRequested(uuid, is_visible) => {
+                    if let Some(window) = self.windows.get_mut(uuid) {
+                        window.settings.is_visible = *is_visible;
+                    }
+                }
+                WindowContextEvent::WindowRenameRequested(uuid, title) => {
+                    if let Some(window) = self.windows.get_mut(uuid) {
+                        window.settings.title = title.to_owned();
+                    }
+                }
+                WindowContextEvent::WindowFullscreenModeRequested(uuid, fullscreen_mode) => {
+                    if let Some(window) = self.windows.get_mut(uuid) {
+                        window.settings.fullscreen_mode = *fullscreen_mode;
+                        self.window_context_event_sender
+                            .send_event(WindowContextEvent::WindowFullscreenModeChanged(
+                                *uuid,
+                                *fullscreen_mode,
+                            ))
+                            .unwrap();
+                    }
+                }
+                WindowContextEvent::WindowRedrawRequested(uuid)
+                    if self.windows.contains_key(uuid) =>
+                {
+                    (self.event_handler)(Box::new(WindowEvent::WindowRedrawRequested(*uuid)));
+                }
+                WindowContextEvent::WindowClosed(uuid) => {
+                    self.windows.remove(uuid);
+                }
+                _ => (),
+            }
+        } else if let Some(headless_event) = event.downcast_ref::<HeadlessEvent>() {
+            match headless_event {
+                HeadlessEvent::Input(uuid, device_id, input) => {
+                    self.handle_input(*uuid, *device_id, *input)
+                }
+                HeadlessEvent::WindowResized(uuid, width, height) => {
+                    self.resize_window(*uuid, *width, *height)
+                }
+                HeadlessEvent::ScaleFactorChanged(uuid, scale_factor) => {
+                    self.change_scale_factor(*uuid, *scale_factor)
+                }
+                HeadlessEvent::Focused(uuid, is_focused) if self.windows.contains_key(uuid) => {
+                    self.window_context_event_sender
+                        .send_event(WindowContextEvent::WindowFocused(*uuid, *is_focused))
+                        .unwrap();
+                    (self.event_handler)(Box::new(WindowEvent::Focused(*uuid, *is_focused)));
+                    let focus_input = if *is_focused {
+                        Input::FocusGained
+                    } else {
+                        Input::FocusLost
+                    };
+                    self.handle_input(Some(*uuid), None, focus_input);
+                }
+                HeadlessEvent::Occluded(uuid, is_occluded) if self.windows.contains_key(uuid) => {
+                    self.window_context_event_sender
+                        .send_event(WindowContextEvent::WindowOccluded(*uuid, *is_occluded))
+                        .unwrap();
+                    (self.event_handler)(Box::new(WindowEvent::Occluded(*uuid, *is_occluded)));
+                }
+                HeadlessEvent::FileHovered(uuid, path) if self.windows.contains_key(uuid) => {
+                    (self.event_handler)(Box::new(WindowEvent::FileHovered(
+                        *uuid,
+                        path.to_owned(),
+                    )));
+                }
+                HeadlessEvent::FileDropped(uuid, path) if self.windows.contains_key(uuid) => {
+                    (self.event_handler)(Box::new(WindowEvent::FileDropped(
+                        *uuid,
+                        path.to_owned(),
+                    )));
+                }
+                HeadlessEvent::FileHoverCancelled(uuid) if self.windows.contains_key(uuid) => {
+                    (self.event_handler)(Box::new(WindowEvent::FileHoverCancelled(*uuid)));
+                }
+                HeadlessEvent::WindowCloseRequested(uuid) if self.windows.contains_key(uuid) => {
+                    (self.event_handler)(Box::new(WindowEvent::WindowClosed(*uuid)));
+                }
+                HeadlessEvent::MonitorsChanged(monitors) => {
+                    self.update_monitors(monitors.to_owned())
+                }
+                _ => (),
+            }
+        } else {
+            (self.event_handler)(event);
+        }
+    }
+
+    fn update_monitors(&mut self, monitors: Vec<Monitor>) {
+        self.monitors = monitors.clone();
+        self.window_context_event_sender
+            .send_event(WindowContextEvent::MonitorsChanged(monitors))
+            .unwrap();
+    }
+
+    fn resize_window(&mut self, uuid: Uuid, width: u32, height: u32) {
+        if let Some(window) = self.windows.get_mut(&uuid) {
+            window.settings.size = (width, height).into();
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowResized(uuid, width, height))
+                .unwrap();
+            (self.event_handler)(Box::new(WindowEvent::WindowResized(uuid, width, height)));
+        }
+    }
+
+    fn change_scale_factor(&mut self, uuid: Uuid, scale_factor: f64) {
+        if let Some(window) = self.windows.get_mut(&uuid) {
+            let old_scale_factor = window.scale_factor;
+            window.scale_factor = scale_factor;
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowScaleFactorChanged(
+                    uuid,
+                    scale_factor,
+                ))
+                .unwrap();
+            (self.event_handler)(Box::new(WindowEvent::ScaleFactorChanged(
+                uuid,
+                scale_factor,
+            )));
+            let size = window
+                .settings
+                .size
+                .to_logical(old_scale_factor)
+                .to_physical(scale_factor);
+            self.resize_window(uuid, size.width, size.height);
+        }
+    }
+
+    fn set_window_mode(&mut self, uuid: Uuid, is_minimized: bool, is_maximized: bool) {
+        let window = match self.windows.get_mut(&uuid) {
+            Some(window) => window,
+            None => return,
+        };
+        let was_minimized = std::mem::replace(&mut window.is_minimized, is_minimized);
+        let was_maximized = std::mem::replace(&mut window.is_maximized, is_maximized);
+
+        if is_minimized != was_minimized {
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowMinimized(uuid, is_minimized))
+                .unwrap();
+        }
+        if is_maximized != was_maximized {
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowMaximized(uuid, is_maximized))
+                .unwrap();
+        }
+        if (is_minimized, is_maximized) != (was_minimized, was_maximized) {
+            let event = if is_minimized {
+                WindowEvent::Minimized(uuid)
+            } else if is_maximized {
+                WindowEvent::Maximized(uuid)
+            } else {
+                WindowEvent::Restored(uuid)
+            };
+            (self.event_handler)(Box::new(event));
+        }
+    }
+
+    /// Get the monitor a window should be opened on.
+    fn window_monitor(&self, settings: &WindowSettings) -> Option<&Monitor> {
+        self.monitors
+            .iter()
+            .find(|monitor| Some(monitor.id) == settings.monitor)
+            .or_else(|| self.monitors.iter().find(|monitor| monitor.is_primary))
+    }
+
+    fn initial_position(&self, settings: &WindowSettings, size: PhysicalSize) -> (i32, i32) {
+        match (settings.position, self.window_monitor(settings)) {
+            (Some(WindowPosition::At(x, y)), _) => (x, y),
+            (Some(WindowPosition::Centered), Some(monitor)) => (
+                monitor.position.0 + (monitor.size.0 as i32 - size.width as i32) / 2,
+                monitor.position.1 + (monitor.size.1 as i32 - size.height as i32) / 2,
+            ),
+            (_, Some(monitor)) => monitor.position,
+            (_, None) => (0, 0),
+        }
+    }
+
+    fn move_window(&mut self, uuid: Uuid, x: i32, y: i32) {
+        if self.windows.contains_key(&uuid) {
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowMoved(uuid, x, y))
+                .unwrap();
+        }
+    }
+
+    fn handle_input(&mut self, uuid: Option<Uuid>, device_id: Option<DeviceId>, input: Input) {
+        if uuid.is_some_and(|uuid| !self.windows.contains_key(&uuid)) {
+            return;
+        }
+        let event_handler = &mut self.event_handler;
+        self.input_states
+            .process(device_id, input, |device_id, input| {
+                (event_handler)(Box::new(WindowEvent::Input(uuid, device_id, input)))
+            });
+    }
+}
+
+#[cfg(test)]
+mod headless_backend_tests {
+    use wolf_engine_events::event_loop::EventLoop;
+    use wolf_engine_input::{
+        keyboard::{Key, KeyCode, LogicalKey, Modifiers},
+        mouse::MouseButton,
+    };
+    use wolf_engine_window::{dpi::LogicalSize, FullscreenMode, MonitorId};
+
+    use super::*;
+
+    fn run_until_exit<F: FnMut(&WindowContext, &HeadlessController, &WindowEvent)>(
+        mut handler: F,
+    ) -> Vec<WindowEvent> {
+        let window_system = init();
+        let context = window_system.context();
+        let controller = window_system.controller();
+        let mut events = Vec::new();
+
+        window_system.run(|event| {
+            if let Some(window_event) = event.downcast_ref::<WindowEvent>() {
+                handler(&context, &controller, window_event);
+                events.push(window_event.to_owned());
+            }
+        });
+
+        events
+    }
+
+    #[test]
+    fn should_emit_life_cycle_events() {
+        let mut window = None;
+        let events = run_until_exit(|context, _, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::EventsCleared => context.exit(),
+            _ => (),
+        });
+        let uuid = window.unwrap().id();
+
+        assert_eq!(
+            events,
+            vec![
+                WindowEvent::Started,
+                WindowEvent::WindowReady(uuid, Ok(())),
+                WindowEvent::EventsCleared,
+                WindowEvent::Exited,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_emit_events_cleared_every_frame() {
+        let mut frames = 0;
+        let events = run_until_exit(|context, _, event| {
+            if *event == WindowEvent::EventsCleared {
+                frames += 1;
+                if frames == 3 {
+                    context.exit();
+                }
+            }
+        });
+
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| **event == WindowEvent::EventsCleared)
+                .count(),
+            3
+        );
+        assert_eq!(events.last(), Some(&WindowEvent::Exited));
+    }
+
+    #[test]
+    fn should_resize_windows() {
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => controller.resize_window(*uuid, 800, 600),
+            WindowEvent::WindowResized(_, _, _) => context.exit(),
+            _ => (),
+        });
+        let window = window.unwrap();
+
+        assert!(events.contains(&WindowEvent::WindowResized(window.id(), 800, 600)));
+        assert_eq!(
+            window.size(),
+            PhysicalSize::new(800, 600),
+            "The window was not resized"
+        );
+    }
+
+    #[test]
+    fn should_apply_fullscreen_modes() {
+        let mut window = None;
+        let mut fullscreen_mode = None;
+        run_until_exit(|context, _, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(_, _) => window
+                .as_ref()
+                .unwrap()
+                .set_fullscreen_mode(Some(FullscreenMode::Borderless)),
+            WindowEvent::EventsCleared => {
+                fullscreen_mode = window.as_ref().unwrap().fullscreen_mode();
+                context.exit();
+            }
+            _ => (),
+        });
+
+        assert_eq!(fullscreen_mode, Some(FullscreenMode::Borderless));
+    }
+
+    #[test]
+    fn should_report_fake_monitors() {
+        let monitor = Monitor {
+            id: MonitorId(0),
+            name: Some("Fake Monitor".to_string()),
+            position: (0, 0),
+            size: (1920, 1080),
+            scale_factor: 2.0,
+            refresh_rate_millihertz: Some(60000),
+            video_modes: Vec::new(),
+            is_primary: true,
+        };
+        let window_system = init().with_monitors(vec![monitor.clone()]);
+        let context = window_system.context();
+        let controller = window_system.controller();
+        let mut primary_monitors = Vec::new();
+
+        window_system.run(|event| {
+            if let Some(window_event) = event.downcast_ref::<WindowEvent>() {
+                match window_event {
+                    WindowEvent::Started => {
+                        primary_monitors.push(context.primary_monitor());
+                        controller.set_monitors(Vec::new());
+                    }
+                    WindowEvent::EventsCleared => {
+                        primary_monitors.push(context.primary_monitor());
+                        context.exit();
+                    }
+                    _ => (),
+                }
+            }
+        });
+
+        assert_eq!(primary_monitors, vec![Some(monitor), None]);
+    }
+
+    #[test]
+    fn should_apply_resize_and_move_requests() {
+        let mut window = None;
+        let mut initial_position = None;
+        let events = run_until_exit(|context, _, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(_, _) => {
+                let window = window.as_ref().unwrap();
+                initial_position = window.position();
+                window.set_size((800, 600));
+                window.set_position((10, 20));
+            }
+            WindowEvent::EventsCleared => context.exit(),
+            _ => (),
+        });
+        let window = window.unwrap();
+
+        assert_eq!(initial_position, Some((0, 0)));
+        assert!(events.contains(&WindowEvent::WindowResized(window.id(), 800, 600)));
+        assert_eq!(
+            window.size(),
+            PhysicalSize::new(800, 600),
+            "The window was not resized"
+        );
+        assert_eq!(
+            window.position(),
+            Some((10, 20)),
+            "The window was not moved"
+        );
+    }
+
+    #[test]
+    fn should_place_windows_on_their_monitor() {
+        let monitors = vec![
+            Monitor {
+                id: MonitorId(0),
+                name: None,
+                position: (0, 0),
+                size: (1920, 1080),
+                scale_factor: 1.0,
+                refresh_rate_millihertz: None,
+                video_modes: Vec::new(),
+                is_primary: true,
+            },
+            Monitor {
+                id: MonitorId(1),
+                name: None,
+                position: (1920, 0),
+                size: (1920, 1080),
+                scale_factor: 1.0,
+                refresh_rate_millihertz: None,
+                video_modes: Vec::new(),
+                is_primary: false,
+            },
+        ];
+        let window_system = init().with_monitors(monitors);
+        let context = window_system.context();
+        let mut windows = Vec::new();
+
+        window_system.run(|event| match event.downcast_ref::<WindowEvent>() {
+            Some(WindowEvent::Started) => {
+                windows.push(context.create_window(WindowSettings::default()));
+                windows.push(context.create_window(
+                    WindowSettings::default().with_position(WindowPosition::At(10, 20)),
+                ));
+                windows.push(
+                    context.create_window(
+                        WindowSettings::default()
+                            .with_size((1280, 720))
+                            .with_position(WindowPosition::Centered),
+                    ),
+                );
+                windows.push(
+                    context.create_window(
+                        WindowSettings::default()
+                            .with_size((1280, 720))
+                            .with_position(WindowPosition::Centered)
+                            .with_monitor(MonitorId(1)),
+                    ),
+                );
+            }
+            Some(WindowEvent::EventsCleared) => context.exit(),
+            _ => (),
+        });
+
+        let positions = windows
+            .iter()
+            .map(|window| window.position())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                Some((0, 0)),
+                Some((10, 20)),
+                Some((320, 180)),
+                Some((2240, 180)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_scale_logical_sizes() {
+        let monitor = Monitor {
+            id: MonitorId(0),
+            name: None,
+            position: (0, 0),
+            size: (3840, 2160),
+            scale_factor: 2.0,
+            refresh_rate_millihertz: None,
+            video_modes: Vec::new(),
+            is_primary: true,
+        };
+        let window_system = init().with_monitors(vec![monitor]);
+        let context = window_system.context();
+        let controller = window_system.controller();
+        let mut window = None;
+        let mut sizes = Vec::new();
+        let mut events = Vec::new();
+
+        window_system.run(|event| {
+            if let Some(window_event) = event.downcast_ref::<WindowEvent>() {
+                match window_event {
+                    WindowEvent::Started => {
+                        window = Some(context.create_window(
+                            WindowSettings::default().with_size(LogicalSize::new(400.0, 300.0)),
+                        ));
+                    }
+                    WindowEvent::WindowReady(uuid, _) => {
+                        let window = window.as_ref().unwrap();
+                        sizes.push((window.scale_factor(), window.size()));
+                        controller.set_scale_factor(*uuid, 1.0);
+                    }
+                    WindowEvent::EventsCleared => {
+                        let window = window.as_ref().unwrap();
+                        sizes.push((window.scale_factor(), window.size()));
+                        context.exit();
+                    }
+                    _ => (),
+                }
+                events.push(window_event.to_owned());
+            }
+        });
+        let uuid = window.unwrap().id();
+
+        assert_eq!(
+            sizes,
+            vec![
+                (2.0, PhysicalSize::new(800, 600)),
+                (1.0, PhysicalSize::new(400, 300)),
+            ]
+        );
+        assert!(events.contains(&WindowEvent::ScaleFactorChanged(uuid, 1.0)));
+        assert!(events.contains(&WindowEvent::WindowResized(uuid, 400, 300)));
+    }
+
+    #[test]
+    fn should_track_window_state() {
+        let mut window = None;
+        let mut states = Vec::new();
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                let window = window.as_ref().unwrap();
+                controller.set_focused(*uuid, true);
+                controller.set_occluded(*uuid, true);
+                window.minimize();
+                window.maximize();
+            }
+            WindowEvent::EventsCleared => {
+                let window = window.as_ref().unwrap();
+                states.push((
+                    window.is_focused(),
+                    window.is_occluded(),
+                    window.is_minimized(),
+                    window.is_maximized(),
+                ));
+                if states.len() == 1 {
+                    window.restore();
+                } else {
+                    context.exit();
+                }
+            }
+            _ => (),
+        });
+        let uuid = window.unwrap().id();
+
+        assert_eq!(
+            states,
+            vec![(true, true, false, true), (true, true, false, false)]
+        );
+        let window_state_events = events
+            .into_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    WindowEvent::Focused(..)
+                        | WindowEvent::Occluded(..)
+                        | WindowEvent::Minimized(..)
+                        | WindowEvent::Maximized(..)
+                        | WindowEvent::Restored(..)
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            window_state_events,
+            vec![
+                WindowEvent::Focused(uuid, true),
+                WindowEvent::Occluded(uuid, true),
+                WindowEvent::Minimized(uuid),
+                WindowEvent::Maximized(uuid),
+                WindowEvent::Restored(uuid),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_emit_close_requests() {
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => controller.request_close(*uuid),
+            WindowEvent::WindowClosed(_) => context.exit(),
+            _ => (),
+        });
+
+        assert!(events.contains(&WindowEvent::WindowClosed(window.unwrap().id())));
+    }
+
+    #[test]
+    fn should_ignore_events_for_closed_windows() {
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                drop(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.resize_window(*uuid, 800, 600);
+                controller.request_close(*uuid);
+                context.exit();
+            }
+            _ => (),
+        });
+
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, WindowEvent::WindowResized(..))));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, WindowEvent::WindowClosed(..))));
+    }
+
+    #[test]
+    fn should_emit_redraw_requests() {
+        let mut window = None;
+        let events = run_until_exit(|context, _, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(_, _) => window.as_ref().unwrap().redraw(),
+            WindowEvent::WindowRedrawRequested(_) => context.exit(),
+            _ => (),
+        });
+
+        assert!(events.contains(&WindowEvent::WindowRedrawRequested(window.unwrap().id())));
+    }
+
+    #[test]
+    fn should_emit_file_drag_and_drop_events() {
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.hover_file(*uuid, "level.ron");
+                controller.cancel_file_hover(*uuid);
+                controller.hover_file(*uuid, "level.ron");
+                controller.drop_file(*uuid, "level.ron");
+            }
+            WindowEvent::FileDropped(_, _) => context.exit(),
+            _ => (),
+        });
+        let uuid = window.unwrap().id();
+
+        let file_events = events
+            .into_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    WindowEvent::FileHovered(_, _)
+                        | WindowEvent::FileDropped(_, _)
+                        | WindowEvent::FileHoverCancelled(_)
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            file_events,
+            vec![
+                WindowEvent::FileHovered(uuid, PathBuf::from("level.ron")),
+                WindowEvent::FileHoverCancelled(uuid),
+                WindowEvent::FileHovered(uuid, PathBuf::from("level.ron")),
+                WindowEvent::FileDropped(uuid, PathBuf::from("level.ron")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_provide_an_in_memory_clipboard() {
+        let mut clipboard_text = None;
+        run_until_exit(|context, _, event| {
+            if let WindowEvent::Started = event {
+                context.clipboard().set_text("Hello, World!").unwrap();
+                clipboard_text = context.clipboard().text().unwrap();
+                context.exit();
+            }
+        });
+
+        assert_eq!(clipboard_text, Some("Hello, World!".to_string()));
+    }
+
+    #[test]
+    fn should_release_held_keys_when_focus_is_lost() {
+        let key = Key {
+            scancode: 0,
+            keycode: Some(KeyCode::A),
+            logical_key: Some(LogicalKey::Character('a')),
+        };
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.send_input(
+                    Some(*uuid),
+                    Some(DeviceId(1)),
+                    Input::KeyPressed {
+                        key,
+                        modifiers: Modifiers::empty(),
+                        is_repeat: false,
+                    },
+                );
+                controller.set_focused(*uuid, false);
+            }
+            WindowEvent::Focused(_, false) => context.exit(),
+            _ => (),
+        });
+        let uuid = window.unwrap().id();
+
+        assert!(events.contains(&WindowEvent::Focused(uuid, false)));
+        assert!(events.contains(&WindowEvent::Input(
+            Some(uuid),
+            Some(DeviceId(1)),
+            Input::KeyReleased {
+                key,
+                modifiers: Modifiers::empty(),
+            },
+        )));
+    }
+
+    #[test]
+    fn should_release_held_keys_for_each_device_when_focus_is_lost() {
+        let key = |keycode| Key {
+            scancode: 0,
+            keycode: Some(keycode),
+            logical_key: Some(LogicalKey::Named(keycode)),
+        };
+        let press = |keycode| Input::KeyPressed {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+            is_repeat: false,
+        };
+        let release = |keycode| Input::KeyReleased {
+            key: key(keycode),
+            modifiers: Modifiers::empty(),
+        };
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.send_input(Some(*uuid), Some(DeviceId(1)), press(KeyCode::W));
+                controller.send_input(Some(*uuid), Some(DeviceId(2)), press(KeyCode::W));
+                controller.send_input(Some(*uuid), Some(DeviceId(2)), press(KeyCode::UpArrow));
+                controller.send_input(Some(*uuid), Some(DeviceId(2)), release(KeyCode::W));
+                controller.set_focused(*uuid, false);
+            }
+            WindowEvent::Focused(_, false) => context.exit(),
+            _ => (),
+        });
+        let uuid = Some(window.unwrap().id());
+
+        let synthesized_inputs = events
+            .into_iter()
+            .skip_while(|event| !matches!(event, WindowEvent::Focused(_, false)))
+            .filter(|event| matches!(event, WindowEvent::Input(..)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            synthesized_inputs,
+            vec![
+                WindowEvent::Input(uuid, Some(DeviceId(1)), release(KeyCode::W)),
+                WindowEvent::Input(uuid, Some(DeviceId(2)), release(KeyCode::UpArrow)),
+                WindowEvent::Input(uuid, None, Input::FocusLost),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_release_held_buttons_and_modifiers_when_focus_is_lost() {
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.send_input(
+                    Some(*uuid),
+                    None,
+                    Input::ModifiersChanged {
+                        modifiers: Modifiers::SHIFT,
+                    },
+                );
+                controller.send_input(
+                    Some(*uuid),
+                    None,
+                    Input::MouseButtonPressed {
+                        button: MouseButton::Left,
+                    },
+                );
+                controller.set_focused(*uuid, false);
+            }
+            WindowEvent::Focused(_, false) => context.exit(),
+            _ => (),
+        });
+        let uuid = window.unwrap().id();
+
+        let synthesized_inputs = events
+            .into_iter()
+            .skip_while(|event| *event != WindowEvent::Focused(uuid, false))
+            .filter_map(|event| match event {
+                WindowEvent::Input(_, _, input) => Some(input),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            synthesized_inputs,
+            vec![
+                Input::ModifiersChanged {
+                    modifiers: Modifiers::empty(),
+                },
+                Input::MouseButtonReleased {
+                    button: MouseButton::Left,
+                },
+                Input::FocusLost,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_inject_inputs() {
+        let input = Input::KeyPressed {
+            key: Key {
+                scancode: 0,
+                keycode: Some(KeyCode::A),
+                logical_key: Some(LogicalKey::Character('a')),
+            },
+            modifiers: Modifiers::empty(),
+            is_repeat: false,
+        };
+        let mut window = None;
+        let events = run_until_exit(|context, controller, event| match event {
+            WindowEvent::Started => {
+                window = Some(context.create_window(WindowSettings::default()));
+            }
+            WindowEvent::WindowReady(uuid, _) => {
+                controller.send_input(Some(*uuid), Some(DeviceId(1)), input)
+            }
+            WindowEvent::Input(_, _, _) => context.exit(),
+            _ => (),
+        });
+
+        assert!(events.contains(&WindowEvent::Input(
+            Some(window.unwrap().id()),
+            Some(DeviceId(1)),
+            input,
+        )));
+    }
+}
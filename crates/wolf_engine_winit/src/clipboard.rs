@@ -0,0 +1,70 @@
+use std::{borrow::Cow, sync::Mutex};
+
+use arboard::{Error as ArboardError, ImageData};
+use wolf_engine_window::{Clipboard, RgbaImage, WindowError};
+
+/// A [`Clipboard`] backed by the operating system's clipboard, through `arboard`.
+///
+/// The connection to the clipboard is opened the first time it's used, so systems without a
+/// clipboard can still open windows.
+#[derive(Default)]
+pub(crate) struct SystemClipboard {
+    clipboard: Mutex<Option<arboard::Clipboard>>,
+}
+
+impl SystemClipboard {
+    fn with_clipboard<T, F>(&self, function: F) -> Result<Option<T>, WindowError>
+    where
+        F: FnOnce(&mut arboard::Clipboard) -> Result<T, ArboardError>,
+    {
+        let mut clipboard = self.clipboard.lock().unwrap();
+        if clipboard.is_none() {
+            *clipboard = Some(arboard::Clipboard::new().map_err(to_window_error)?);
+        }
+        match function(clipboard.as_mut().unwrap()) {
+            Ok(value) => Ok(Some(value)),
+            Err(ArboardError::ContentNotAvailable) => Ok(None),
+            Err(error) => Err(to_window_error(error)),
+        }
+    }
+}
+
+impl Clipboard for SystemClipboard {
+    fn text(&self) -> Result<Option<String>, WindowError> {
+        self.with_clipboard(|clipboard| clipboard.get_text())
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), WindowError> {
+        self.with_clipboard(|clipboard| clipboard.set_text(text))
+            .map(|_| ())
+    }
+
+    fn image(&self) -> Result<Option<RgbaImage>, WindowError> {
+        match self.with_clipboard(|clipboard| clipboard.get_image())? {
+            Some(image) => RgbaImage::new(
+                image.width as u32,
+                image.height as u32,
+                image.bytes.into_owned(),
+            )
+            .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn set_image(&self, image: &RgbaImage) -> Result<(), WindowError> {
+        let image_data = ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: Cow::Borrowed(image.rgba()),
+        };
+        self.with_clipboard(|clipboard| clipboard.set_image(image_data))
+            .map(|_| ())
+    }
+}
+
+fn to_window_error(error: ArboardError) -> WindowError {
+    match error {
+        ArboardError::ClipboardNotSupported => WindowError::UnsupportedError(error.to_string()),
+        error => WindowError::ClipboardError(error.to_string()),
+    }
+}
@@ -2,29 +2,41 @@ use std::{collections::HashMap, sync::Arc};
 
 use winit::{
     application::ApplicationHandler,
-    dpi::PhysicalSize,
-    event::{StartCause, WindowEvent as WinitEvent},
+    dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Size as WinitSize},
+    error::ExternalError,
+    event::{DeviceEvent, DeviceId as WinitDeviceId, StartCause, WindowEvent as WinitEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
-    window::{Window, WindowAttributes, WindowId},
+    monitor::{MonitorHandle, VideoModeHandle},
+    window::{
+        CursorGrabMode as WinitCursorGrabMode, CursorIcon as WinitCursorIcon,
+        CustomCursor as WinitCustomCursor, Fullscreen, Icon, Window, WindowAttributes, WindowId,
+        WindowLevel as WinitWindowLevel,
+    },
 };
 use wolf_engine_events::{
     dynamic::AnyEvent,
     mpsc::{self, MpscEventReceiver, MpscEventSender},
     EventReceiver, EventSender,
 };
-use wolf_engine_input::ToInput;
+use wolf_engine_input::{device::DeviceId, DeviceInputStates, Input, ToInput};
 use wolf_engine_window::{
     backend::{
         event::{WindowContextEvent, WindowContextEventSender},
         WindowSystem,
     },
+    dpi::Size,
     raw_window_handle::WindowHandle,
-    Uuid, WindowContext, WindowError, WindowEvent, WindowSettings,
+    CursorGrabMode, CursorIcon, CustomCursor, FullscreenMode, Monitor, MonitorId, Uuid, VideoMode,
+    WindowContext, WindowError, WindowEvent, WindowLevel, WindowPosition, WindowSettings,
 };
 
+mod clipboard;
+use clipboard::SystemClipboard;
+
 pub fn init() -> Result<WinitBackend, WindowError> {
     let (event_sender, event_receiver) = mpsc::event_queue();
-    let (window_context, window_context_event_sender) = WindowContext::new(event_sender.clone());
+    let (window_context, window_context_event_sender) =
+        WindowContext::with_clipboard(event_sender.clone(), SystemClipboard::default());
     match EventLoop::new() {
         Ok(event_loop) => Ok(WinitBackend {
             event_sender,
@@ -32,6 +44,7 @@ pub fn init() -> Result<WinitBackend, WindowError> {
             window_context,
             window_context_event_sender,
             event_loop,
+            input_states: DeviceInputStates::default(),
         }),
         Err(error) => Err(WindowError::InitError(error.to_string())),
     }
@@ -43,6 +56,17 @@ pub struct WinitBackend {
     window_context: WindowContext,
     window_context_event_sender: WindowContextEventSender,
     event_loop: EventLoop<()>,
+    input_states: DeviceInputStates,
+}
+
+impl WinitBackend {
+    /// Set whether or not the primary touch should be emulated as mouse input.
+    ///
+    /// See [`DeviceInputStates::with_mouse_emulation()`] for details.
+    pub fn with_mouse_emulation(mut self, is_emulating_mouse: bool) -> Self {
+        self.input_states = self.input_states.with_mouse_emulation(is_emulating_mouse);
+        self
+    }
 }
 
 impl WindowSystem for WinitBackend {
@@ -63,6 +87,7 @@ impl wolf_engine_events::event_loop::EventLoop<AnyEvent> for WinitBackend {
             self.event_receiver,
             self.window_context,
             self.window_context_event_sender,
+            self.input_states,
         );
         let event_loop = self.event_loop;
 
@@ -77,10 +102,16 @@ struct WinitApp<H: FnMut(AnyEvent)> {
     window_context: WindowContext,
     window_context_event_sender: WindowContextEventSender,
     is_suspended: bool,
+    input_states: DeviceInputStates,
 
     pending_windows: Vec<(Uuid, WindowSettings)>,
+    pending_requests: Vec<AnyEvent>,
     id_map: HashMap<WindowId, Uuid>,
     windows: HashMap<Uuid, Arc<Window>>,
+    device_ids: HashMap<WinitDeviceId, DeviceId>,
+    monitor_handles: Vec<MonitorHandle>,
+    monitors: Option<Vec<Monitor>>,
+    window_modes: HashMap<Uuid, (bool, bool)>,
 }
 
 impl<H: FnMut(AnyEvent)> WinitApp<H> {
@@ -90,6 +121,7 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
         event_receiver: MpscEventReceiver<AnyEvent>,
         window_context: WindowContext,
         window_context_event_sender: WindowContextEventSender,
+        input_states: DeviceInputStates,
     ) -> Self {
         Self {
             event_handler,
@@ -98,10 +130,16 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
             window_context,
             window_context_event_sender,
             is_suspended: true,
+            input_states,
 
             pending_windows: Vec::new(),
+            pending_requests: Vec::new(),
             id_map: HashMap::new(),
             windows: HashMap::new(),
+            device_ids: HashMap::new(),
+            monitor_handles: Vec::new(),
+            monitors: None,
+            window_modes: HashMap::new(),
         }
     }
 
@@ -113,6 +151,10 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
     }
 
     fn handle_event(&mut self, event_loop: &ActiveEventLoop, event: AnyEvent) {
+        if self.is_pending_request(&event) {
+            self.pending_requests.push(event);
+            return;
+        }
         if let Some(context_event) = event.downcast_ref::<WindowContextEvent>() {
             match context_event {
                 WindowContextEvent::Exited => {
@@ -123,32 +165,207 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
                     .pending_windows
                     .push((uuid.to_owned(), settings.to_owned())),
 
+                WindowContextEvent::WindowFullscreenModeRequested(uuid, fullscreen_mode) => {
+                    if let Some(window) = self.windows.get(uuid).cloned() {
+                        let monitor = window
+                            .current_monitor()
+                            .or_else(|| event_loop.primary_monitor());
+                        let fullscreen =
+                            fullscreen_mode.and_then(|mode| winit_fullscreen(mode, monitor));
+                        let applied_mode = fullscreen.as_ref().and(*fullscreen_mode);
+                        window.set_fullscreen(fullscreen);
+                        self.window_context_event_sender
+                            .send_event(WindowContextEvent::WindowFullscreenModeChanged(
+                                *uuid,
+                                applied_mode,
+                            ))
+                            .unwrap();
+                    }
+                }
                 WindowContextEvent::WindowRedrawRequested(uuid) => {
                     if let Some(window) = self.windows.get(uuid) {
                         window.request_redraw();
                     }
                 }
+                WindowContextEvent::WindowResizeRequested(uuid, size) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        // If the window was resized immediately, no `Resized` event is emitted.
+                        if let Some(new_size) = window.request_inner_size(to_winit_size(*size)) {
+                            self.resize_window(*uuid, new_size.width, new_size.height);
+                        }
+                    }
+                }
+                WindowContextEvent::WindowMoveRequested(uuid, x, y) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_outer_position(PhysicalPosition::new(*x, *y));
+                    }
+                }
+                WindowContextEvent::WindowVisibilityRequested(uuid, is_visible) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_visible(*is_visible);
+                    }
+                }
+                WindowContextEvent::WindowMinSizeRequested(uuid, min_size) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_min_inner_size(min_size.map(to_winit_size));
+                    }
+                }
+                WindowContextEvent::WindowMaxSizeRequested(uuid, max_size) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_max_inner_size(max_size.map(to_winit_size));
+                    }
+                }
+                WindowContextEvent::WindowMinimizeRequested(uuid) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_minimized(true);
+                    }
+                }
+                WindowContextEvent::WindowMaximizeRequested(uuid) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_maximized(true);
+                    }
+                }
+                WindowContextEvent::WindowRestoreRequested(uuid) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_minimized(false);
+                        window.set_maximized(false);
+                    }
+                }
+                WindowContextEvent::WindowCursorVisibilityRequested(uuid, is_visible) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_cursor_visible(*is_visible);
+                    }
+                }
+                WindowContextEvent::WindowCursorGrabRequested(uuid, grab_mode) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        let result = window.set_cursor_grab(to_winit_grab_mode(*grab_mode));
+                        self.report_request_result(*uuid, result);
+                    }
+                }
+                WindowContextEvent::WindowCursorIconRequested(uuid, icon) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        window.set_cursor(to_winit_cursor_icon(*icon));
+                    }
+                }
+                WindowContextEvent::WindowCustomCursorRequested(uuid, cursor) => {
+                    if let Some(window) = self.windows.get(uuid).cloned() {
+                        match winit_custom_cursor(event_loop, cursor) {
+                            Ok(cursor) => window.set_cursor(cursor),
+                            Err(error) => (self.event_handler)(Box::new(
+                                WindowEvent::RequestFailed(*uuid, error),
+                            )),
+                        }
+                    }
+                }
+                WindowContextEvent::WindowCursorMoveRequested(uuid, x, y) => {
+                    if let Some(window) = self.windows.get(uuid) {
+                        let result =
+                            window.set_cursor_position(PhysicalPosition::new(*x as f64, *y as f64));
+                        self.report_request_result(*uuid, result);
+                    }
+                }
                 WindowContextEvent::WindowClosed(uuid) => {
+                    self.pending_windows
+                        .retain(|(pending_uuid, _)| pending_uuid != uuid);
+                    self.pending_requests
+                        .retain(|request| requested_window(request) != Some(*uuid));
                     if let Some(window) = self.windows.remove(uuid) {
                         let _ = self.id_map.remove(&window.id());
                     }
+                    self.window_modes.remove(uuid);
                 }
                 _ => (),
             }
         }
     }
 
+    /// Check if an event is a request for a window which hasn't been created yet.
+    ///
+    /// Windows are only created after all events have been processed, so requests made right
+    /// after [`WindowContext::create_window()`] are held until the window exists.
+    fn is_pending_request(&self, event: &AnyEvent) -> bool {
+        requested_window(event).is_some_and(|uuid| {
+            self.pending_windows
+                .iter()
+                .any(|(pending_uuid, _)| *pending_uuid == uuid)
+        })
+    }
+
+    /// Apply the requests which were made before a window was created.
+    fn apply_pending_requests(&mut self, event_loop: &ActiveEventLoop, uuid: Uuid) {
+        let (requests, pending_requests) = self
+            .pending_requests
+            .drain(..)
+            .partition(|request| requested_window(request) == Some(uuid));
+        self.pending_requests = pending_requests;
+        for request in requests {
+            self.handle_event(event_loop, request);
+        }
+    }
+
     fn create_windows(&mut self, event_loop: &ActiveEventLoop) {
         if self.is_suspended {
             return;
         }
 
         while let Some((uuid, settings)) = self.pending_windows.pop() {
+            // Disconnected monitors keep their ids, but windows can't be opened on them.
+            let selected_monitor = settings
+                .monitor
+                .and_then(|monitor_id| self.monitor_handle(monitor_id))
+                .filter(|monitor_handle| {
+                    event_loop
+                        .available_monitors()
+                        .any(|available| available == *monitor_handle)
+                });
+            let monitor = selected_monitor
+                .clone()
+                .or_else(|| event_loop.primary_monitor())
+                .or_else(|| event_loop.available_monitors().next());
+            let position = match settings.position {
+                Some(WindowPosition::At(x, y)) => Some(PhysicalPosition::new(x, y)),
+                Some(WindowPosition::Centered) => monitor.as_ref().map(|monitor| {
+                    let size = settings.size.to_physical(monitor.scale_factor());
+                    centered_position(monitor, size)
+                }),
+                None => selected_monitor.map(|monitor| monitor.position()),
+            };
+            let fullscreen = settings
+                .fullscreen_mode
+                .and_then(|mode| winit_fullscreen(mode, monitor));
+            if fullscreen.is_none() && settings.fullscreen_mode.is_some() {
+                self.window_context_event_sender
+                    .send_event(WindowContextEvent::WindowFullscreenModeChanged(uuid, None))
+                    .unwrap();
+            }
             let window_attributes = WindowAttributes::default()
                 .with_title(settings.title)
-                .with_inner_size(PhysicalSize::new(settings.size.0, settings.size.1))
+                .with_inner_size(to_winit_size(settings.size))
                 .with_visible(settings.is_visible)
-                .with_resizable(settings.is_resizable);
+                .with_resizable(settings.is_resizable)
+                .with_fullscreen(fullscreen)
+                .with_decorations(settings.has_decorations)
+                .with_transparent(settings.is_transparent)
+                .with_window_level(match settings.window_level {
+                    WindowLevel::Normal => WinitWindowLevel::Normal,
+                    WindowLevel::AlwaysOnTop => WinitWindowLevel::AlwaysOnTop,
+                    WindowLevel::AlwaysOnBottom => WinitWindowLevel::AlwaysOnBottom,
+                })
+                .with_window_icon(settings.icon.and_then(|icon| {
+                    Icon::from_rgba(icon.rgba().to_vec(), icon.width(), icon.height()).ok()
+                }));
+            let window_attributes = match position {
+                Some(position) => window_attributes.with_position(position),
+                None => window_attributes,
+            };
+            let window_attributes = match settings.min_size {
+                Some(min_size) => window_attributes.with_min_inner_size(to_winit_size(min_size)),
+                None => window_attributes,
+            };
+            let window_attributes = match settings.max_size {
+                Some(max_size) => window_attributes.with_max_inner_size(to_winit_size(max_size)),
+                None => window_attributes,
+            };
             let window = Arc::new(
                 event_loop
                     .create_window(window_attributes)
@@ -156,11 +373,30 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
             );
             let window_handle = WindowHandle::new(window.clone());
 
+            if let Ok(position) = window.outer_position() {
+                self.move_window(uuid, position.x, position.y);
+            }
+            let size = window.inner_size();
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowScaleFactorChanged(
+                    uuid,
+                    window.scale_factor(),
+                ))
+                .unwrap();
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowResized(
+                    uuid,
+                    size.width,
+                    size.height,
+                ))
+                .unwrap();
+
             self.id_map.insert(window.id(), uuid);
             self.windows.insert(uuid, window);
             self.window_context
                 .insert_window_handle(uuid, window_handle);
             (self.event_handler)(Box::new(WindowEvent::WindowReady(uuid, Ok(()))));
+            self.apply_pending_requests(event_loop, uuid);
         }
     }
 
@@ -170,11 +406,148 @@ impl<H: FnMut(AnyEvent)> WinitApp<H> {
             .unwrap();
         (self.event_handler)(Box::new(WindowEvent::WindowResized(uuid, width, height)))
     }
+
+    /// Check if a window has been minimized, maximized, or restored, and emit events if it has.
+    ///
+    /// Winit doesn't have events for these changes, so this is checked when the window is
+    /// resized, or occluded.
+    fn update_window_mode(&mut self, uuid: Uuid) {
+        let window = match self.windows.get(&uuid) {
+            Some(window) => window,
+            None => return,
+        };
+        let window_mode = (
+            window.is_minimized().unwrap_or(false),
+            window.is_maximized(),
+        );
+        let (was_minimized, was_maximized) = self
+            .window_modes
+            .insert(uuid, window_mode)
+            .unwrap_or_default();
+        let (is_minimized, is_maximized) = window_mode;
+
+        if is_minimized != was_minimized {
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowMinimized(uuid, is_minimized))
+                .unwrap();
+        }
+        if is_maximized != was_maximized {
+            self.window_context_event_sender
+                .send_event(WindowContextEvent::WindowMaximized(uuid, is_maximized))
+                .unwrap();
+        }
+        if window_mode != (was_minimized, was_maximized) {
+            let event = if is_minimized {
+                WindowEvent::Minimized(uuid)
+            } else if is_maximized {
+                WindowEvent::Maximized(uuid)
+            } else {
+                WindowEvent::Restored(uuid)
+            };
+            (self.event_handler)(Box::new(event));
+        }
+    }
+
+    /// Emit a [`WindowEvent::RequestFailed`] event if Winit refused a request.
+    fn report_request_result(&mut self, uuid: Uuid, result: Result<(), ExternalError>) {
+        if let Err(error) = result {
+            (self.event_handler)(Box::new(WindowEvent::RequestFailed(
+                uuid,
+                WindowError::UnsupportedError(error.to_string()),
+            )));
+        }
+    }
+
+    fn move_window(&mut self, uuid: Uuid, x: i32, y: i32) {
+        self.window_context_event_sender
+            .send_event(WindowContextEvent::WindowMoved(uuid, x, y))
+            .unwrap();
+    }
+
+    fn handle_input(&mut self, uuid: Option<Uuid>, device_id: Option<DeviceId>, input: Input) {
+        let event_handler = &mut self.event_handler;
+        self.input_states
+            .process(device_id, input, |device_id, input| {
+                (event_handler)(Box::new(WindowEvent::Input(uuid, device_id, input)))
+            });
+    }
+
+    /// Send the currently-connected monitors to the [`WindowContext`], if they have changed.
+    ///
+    /// Winit has no event for monitors being connected, or disconnected, so they are checked
+    /// again when the app is resumed, or a window is moved, or changes scale factor.
+    fn update_monitors(&mut self, event_loop: &ActiveEventLoop) {
+        let primary_monitor = event_loop.primary_monitor();
+        let monitors = event_loop
+            .available_monitors()
+            .map(|monitor_handle| {
+                let is_primary = primary_monitor.as_ref() == Some(&monitor_handle);
+                self.monitor(monitor_handle, is_primary)
+            })
+            .collect::<Vec<_>>();
+        if self.monitors.as_ref() == Some(&monitors) {
+            return;
+        }
+        self.monitors = Some(monitors.clone());
+        self.window_context_event_sender
+            .send_event(WindowContextEvent::MonitorsChanged(monitors))
+            .unwrap();
+    }
+
+    fn monitor(&mut self, monitor_handle: MonitorHandle, is_primary: bool) -> Monitor {
+        let PhysicalPosition { x, y } = monitor_handle.position();
+        let PhysicalSize { width, height } = monitor_handle.size();
+        Monitor {
+            id: self.monitor_id(&monitor_handle),
+            name: monitor_handle.name(),
+            position: (x, y),
+            size: (width, height),
+            scale_factor: monitor_handle.scale_factor(),
+            refresh_rate_millihertz: monitor_handle.refresh_rate_millihertz(),
+            video_modes: monitor_handle
+                .video_modes()
+                .map(|video_mode_handle| to_video_mode(&video_mode_handle))
+                .collect(),
+            is_primary,
+        }
+    }
+
+    /// Get the stable [`MonitorId`] for a Winit monitor handle, assigning a new one if it hasn't
+    /// been seen before.
+    fn monitor_id(&mut self, monitor_handle: &MonitorHandle) -> MonitorId {
+        let index = match self
+            .monitor_handles
+            .iter()
+            .position(|known_handle| known_handle == monitor_handle)
+        {
+            Some(index) => index,
+            None => {
+                self.monitor_handles.push(monitor_handle.clone());
+                self.monitor_handles.len() - 1
+            }
+        };
+        MonitorId(index as u64)
+    }
+
+    fn monitor_handle(&self, monitor_id: MonitorId) -> Option<MonitorHandle> {
+        self.monitor_handles.get(monitor_id.0 as usize).cloned()
+    }
+
+    /// Get the stable [`DeviceId`] for a Winit device id, assigning a new one if it hasn't been
+    /// seen before.
+    fn device_id(&mut self, winit_device_id: WinitDeviceId) -> DeviceId {
+        let next_device_id = DeviceId(self.device_ids.len() as u64);
+        *self
+            .device_ids
+            .entry(winit_device_id)
+            .or_insert(next_device_id)
+    }
 }
 
 impl<H: FnMut(AnyEvent)> ApplicationHandler for WinitApp<H> {
-    fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         self.is_suspended = false;
+        self.update_monitors(event_loop);
     }
 
     fn suspended(&mut self, _event_loop: &ActiveEventLoop) {
@@ -184,7 +557,10 @@ impl<H: FnMut(AnyEvent)> ApplicationHandler for WinitApp<H> {
     fn new_events(&mut self, event_loop: &ActiveEventLoop, cause: StartCause) {
         event_loop.set_control_flow(ControlFlow::Poll);
         match cause {
-            StartCause::Init => (self.event_handler)(Box::new(WindowEvent::Started)),
+            StartCause::Init => {
+                self.update_monitors(event_loop);
+                (self.event_handler)(Box::new(WindowEvent::Started))
+            }
             StartCause::Poll => {
                 self.process_events(event_loop);
                 self.create_windows(event_loop);
@@ -195,7 +571,7 @@ impl<H: FnMut(AnyEvent)> ApplicationHandler for WinitApp<H> {
 
     fn window_event(
         &mut self,
-        _event_loop: &ActiveEventLoop,
+        event_loop: &ActiveEventLoop,
         window_id: WindowId,
         event: winit::event::WindowEvent,
     ) {
@@ -209,14 +585,63 @@ impl<H: FnMut(AnyEvent)> ApplicationHandler for WinitApp<H> {
                 (self.event_handler)(Box::new(WindowEvent::WindowClosed(uuid)))
             }
             WinitEvent::Resized(new_size) => {
-                self.resize_window(uuid, new_size.width, new_size.height)
+                self.resize_window(uuid, new_size.width, new_size.height);
+                self.update_window_mode(uuid);
+            }
+            WinitEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.update_monitors(event_loop);
+                self.window_context_event_sender
+                    .send_event(WindowContextEvent::WindowScaleFactorChanged(
+                        uuid,
+                        scale_factor,
+                    ))
+                    .unwrap();
+                (self.event_handler)(Box::new(WindowEvent::ScaleFactorChanged(
+                    uuid,
+                    scale_factor,
+                )))
+            }
+            WinitEvent::Moved(new_position) => {
+                self.update_monitors(event_loop);
+                self.move_window(uuid, new_position.x, new_position.y);
+                (self.event_handler)(Box::new(WindowEvent::Moved(
+                    uuid,
+                    new_position.x,
+                    new_position.y,
+                )))
+            }
+            WinitEvent::Focused(is_focused) => {
+                self.window_context_event_sender
+                    .send_event(WindowContextEvent::WindowFocused(uuid, is_focused))
+                    .unwrap();
+                (self.event_handler)(Box::new(WindowEvent::Focused(uuid, is_focused)));
+                if let Some(input) = event.to_input() {
+                    self.handle_input(Some(uuid), None, input);
+                }
+            }
+            WinitEvent::Occluded(is_occluded) => {
+                self.window_context_event_sender
+                    .send_event(WindowContextEvent::WindowOccluded(uuid, is_occluded))
+                    .unwrap();
+                (self.event_handler)(Box::new(WindowEvent::Occluded(uuid, is_occluded)));
+                self.update_window_mode(uuid);
             }
             WinitEvent::RedrawRequested => {
                 (self.event_handler)(Box::new(WindowEvent::WindowRedrawRequested(uuid)))
             }
+            WinitEvent::HoveredFile(path) => {
+                (self.event_handler)(Box::new(WindowEvent::FileHovered(uuid, path)))
+            }
+            WinitEvent::DroppedFile(path) => {
+                (self.event_handler)(Box::new(WindowEvent::FileDropped(uuid, path)))
+            }
+            WinitEvent::HoveredFileCancelled => {
+                (self.event_handler)(Box::new(WindowEvent::FileHoverCancelled(uuid)))
+            }
             _ => {
                 if let Some(input) = event.to_input() {
-                    (self.event_handler)(Box::new(WindowEvent::Input(Some(uuid), input)))
+                    let device_id = event_device_id(&event).map(|id| self.device_id(id));
+                    self.handle_input(Some(uuid), device_id, input);
                 }
             }
         }
@@ -225,11 +650,179 @@ impl<H: FnMut(AnyEvent)> ApplicationHandler for WinitApp<H> {
     fn device_event(
         &mut self,
         _event_loop: &ActiveEventLoop,
-        _device_id: winit::event::DeviceId,
-        event: winit::event::DeviceEvent,
+        device_id: WinitDeviceId,
+        event: DeviceEvent,
     ) {
-        if let Some(input) = event.to_input() {
-            (self.event_handler)(Box::new(WindowEvent::Input(None, input)))
+        let device_id = self.device_id(device_id);
+        match event {
+            DeviceEvent::Added => {
+                (self.event_handler)(Box::new(WindowEvent::DeviceConnected(device_id)))
+            }
+            DeviceEvent::Removed => {
+                (self.event_handler)(Box::new(WindowEvent::DeviceDisconnected(device_id)))
+            }
+            event => {
+                if let Some(input) = event.to_input() {
+                    self.handle_input(None, Some(device_id), input);
+                }
+            }
         }
     }
 }
+
+/// Get the Winit equivalent of a [`FullscreenMode`] on the given monitor.
+///
+/// Returns `None` if the monitor doesn't support the requested video mode.
+fn winit_fullscreen(
+    fullscreen_mode: FullscreenMode,
+    monitor: Option<MonitorHandle>,
+) -> Option<Fullscreen> {
+    match fullscreen_mode {
+        FullscreenMode::Borderless => Some(Fullscreen::Borderless(monitor)),
+        FullscreenMode::Exclusive(video_mode) => monitor?
+            .video_modes()
+            .find(|video_mode_handle| to_video_mode(video_mode_handle) == video_mode)
+            .map(Fullscreen::Exclusive),
+    }
+}
+
+/// Get the position which centers a window of the given size on a monitor.
+fn centered_position(
+    monitor: &MonitorHandle,
+    size: wolf_engine_window::dpi::PhysicalSize,
+) -> PhysicalPosition<i32> {
+    let PhysicalPosition { x, y } = monitor.position();
+    let PhysicalSize { width, height } = monitor.size();
+    PhysicalPosition::new(
+        x + (width as i32 - size.width as i32) / 2,
+        y + (height as i32 - size.height as i32) / 2,
+    )
+}
+
+fn to_winit_grab_mode(grab_mode: CursorGrabMode) -> WinitCursorGrabMode {
+    match grab_mode {
+        CursorGrabMode::None => WinitCursorGrabMode::None,
+        CursorGrabMode::Confined => WinitCursorGrabMode::Confined,
+        CursorGrabMode::Locked => WinitCursorGrabMode::Locked,
+    }
+}
+
+fn to_winit_cursor_icon(icon: CursorIcon) -> WinitCursorIcon {
+    match icon {
+        CursorIcon::Default => WinitCursorIcon::Default,
+        CursorIcon::ContextMenu => WinitCursorIcon::ContextMenu,
+        CursorIcon::Help => WinitCursorIcon::Help,
+        CursorIcon::Pointer => WinitCursorIcon::Pointer,
+        CursorIcon::Progress => WinitCursorIcon::Progress,
+        CursorIcon::Wait => WinitCursorIcon::Wait,
+        CursorIcon::Cell => WinitCursorIcon::Cell,
+        CursorIcon::Crosshair => WinitCursorIcon::Crosshair,
+        CursorIcon::Text => WinitCursorIcon::Text,
+        CursorIcon::VerticalText => WinitCursorIcon::VerticalText,
+        CursorIcon::Alias => WinitCursorIcon::Alias,
+        CursorIcon::Copy => WinitCursorIcon::Copy,
+        CursorIcon::Move => WinitCursorIcon::Move,
+        CursorIcon::NoDrop => WinitCursorIcon::NoDrop,
+        CursorIcon::NotAllowed => WinitCursorIcon::NotAllowed,
+        CursorIcon::Grab => WinitCursorIcon::Grab,
+        CursorIcon::Grabbing => WinitCursorIcon::Grabbing,
+        CursorIcon::EResize => WinitCursorIcon::EResize,
+        CursorIcon::NResize => WinitCursorIcon::NResize,
+        CursorIcon::NeResize => WinitCursorIcon::NeResize,
+        CursorIcon::NwResize => WinitCursorIcon::NwResize,
+        CursorIcon::SResize => WinitCursorIcon::SResize,
+        CursorIcon::SeResize => WinitCursorIcon::SeResize,
+        CursorIcon::SwResize => WinitCursorIcon::SwResize,
+        CursorIcon::WResize => WinitCursorIcon::WResize,
+        CursorIcon::EwResize => WinitCursorIcon::EwResize,
+        CursorIcon::NsResize => WinitCursorIcon::NsResize,
+        CursorIcon::NeswResize => WinitCursorIcon::NeswResize,
+        CursorIcon::NwseResize => WinitCursorIcon::NwseResize,
+        CursorIcon::ColResize => WinitCursorIcon::ColResize,
+        CursorIcon::RowResize => WinitCursorIcon::RowResize,
+        CursorIcon::AllScroll => WinitCursorIcon::AllScroll,
+        CursorIcon::ZoomIn => WinitCursorIcon::ZoomIn,
+        CursorIcon::ZoomOut => WinitCursorIcon::ZoomOut,
+        CursorIcon::DndAsk => WinitCursorIcon::DndAsk,
+        CursorIcon::AllResize => WinitCursorIcon::AllResize,
+        _ => WinitCursorIcon::Default,
+    }
+}
+
+fn winit_custom_cursor(
+    event_loop: &ActiveEventLoop,
+    cursor: &CustomCursor,
+) -> Result<WinitCustomCursor, WindowError> {
+    let image = cursor.image();
+    let to_u16 = |value: u32| {
+        u16::try_from(value).map_err(|_| {
+            WindowError::InvalidImageError(format!(
+                "The {}x{} cursor image is too large",
+                image.width(),
+                image.height()
+            ))
+        })
+    };
+    let source = WinitCustomCursor::from_rgba(
+        image.rgba(),
+        to_u16(image.width())?,
+        to_u16(image.height())?,
+        to_u16(cursor.hotspot().0)?,
+        to_u16(cursor.hotspot().1)?,
+    )
+    .map_err(|error| WindowError::InvalidImageError(error.to_string()))?;
+    Ok(event_loop.create_custom_cursor(source))
+}
+
+fn to_winit_size(size: Size) -> WinitSize {
+    match size {
+        Size::Physical(size) => PhysicalSize::new(size.width, size.height).into(),
+        Size::Logical(size) => LogicalSize::new(size.width, size.height).into(),
+    }
+}
+
+fn to_video_mode(video_mode_handle: &VideoModeHandle) -> VideoMode {
+    let size = video_mode_handle.size();
+    VideoMode {
+        size: (size.width, size.height),
+        bit_depth: video_mode_handle.bit_depth(),
+        refresh_rate_millihertz: video_mode_handle.refresh_rate_millihertz(),
+    }
+}
+
+/// Get the window a [`WindowContextEvent`] request is for, if the event is a request.
+fn requested_window(event: &AnyEvent) -> Option<Uuid> {
+    match event.downcast_ref::<WindowContextEvent>()? {
+        WindowContextEvent::WindowRenameRequested(uuid, _)
+        | WindowContextEvent::WindowRedrawRequested(uuid)
+        | WindowContextEvent::WindowResizeRequested(uuid, _)
+        | WindowContextEvent::WindowMoveRequested(uuid, _, _)
+        | WindowContextEvent::WindowVisibilityRequested(uuid, _)
+        | WindowContextEvent::WindowMinSizeRequested(uuid, _)
+        | WindowContextEvent::WindowMaxSizeRequested(uuid, _)
+        | WindowContextEvent::WindowMinimizeRequested(uuid)
+        | WindowContextEvent::WindowMaximizeRequested(uuid)
+        | WindowContextEvent::WindowRestoreRequested(uuid)
+        | WindowContextEvent::WindowFullscreenModeRequested(uuid, _)
+        | WindowContextEvent::WindowCursorVisibilityRequested(uuid, _)
+        | WindowContextEvent::WindowCursorGrabRequested(uuid, _)
+        | WindowContextEvent::WindowCursorIconRequested(uuid, _)
+        | WindowContextEvent::WindowCustomCursorRequested(uuid, _)
+        | WindowContextEvent::WindowCursorMoveRequested(uuid, _, _) => Some(*uuid),
+        _ => None,
+    }
+}
+
+/// Get the id of the device which produced a window event, if it has one.
+fn event_device_id(event: &WinitEvent) -> Option<WinitDeviceId> {
+    match event {
+        WinitEvent::KeyboardInput { device_id, .. }
+        | WinitEvent::CursorMoved { device_id, .. }
+        | WinitEvent::CursorEntered { device_id }
+        | WinitEvent::CursorLeft { device_id }
+        | WinitEvent::MouseWheel { device_id, .. }
+        | WinitEvent::MouseInput { device_id, .. }
+        | WinitEvent::Touch(winit::event::Touch { device_id, .. }) => Some(*device_id),
+        _ => None,
+    }
+}
@@ -5,15 +5,16 @@ use winit::{
     window::WindowAttributes,
 };
 use wolf_engine::input::ToInput;
-use wolf_engine_input::Input;
+use wolf_engine_input::{Input, InputState};
 
 #[allow(deprecated)]
 pub fn main() {
     let event_loop = EventLoop::new().unwrap();
     let mut window = None;
+    let mut input_state = InputState::default();
     let _ = event_loop.run(|event, window_target| {
         if let Some(input) = event.to_input() {
-            process_input(&input);
+            input_state.process(input, |input| process_input(&input));
         }
         match event {
             Event::Resumed => {
@@ -42,8 +43,17 @@ pub fn main() {
 
 fn process_input(input: &Input) {
     match input {
-        Input::KeyPressed { key, is_repeat } => println!("Key pressed: {key:?}, {is_repeat:?}"),
-        Input::KeyReleased { key } => println!("Key released: {key:?}"),
+        Input::KeyPressed {
+            key,
+            modifiers,
+            is_repeat,
+        } => println!("Key pressed: {key:?}, {modifiers:?}, {is_repeat:?}"),
+        Input::KeyReleased { key, modifiers } => println!("Key released: {key:?}, {modifiers:?}"),
+        Input::ModifiersChanged { modifiers } => println!("Modifiers changed: {modifiers:?}"),
+        Input::CursorEntered => println!("Cursor entered"),
+        Input::CursorLeft => println!("Cursor left"),
+        Input::FocusGained => println!("Focus gained"),
+        Input::FocusLost => println!("Focus lost"),
         Input::MouseMovedTo { x, y } => println!("Mouse Moved to: {x}px, {y}px"),
         Input::MouseMoved { delta_x, delta_y } => {
             println!("Mouse Moved: {delta_x}, {delta_y}")
@@ -54,8 +64,17 @@ fn process_input(input: &Input) {
         Input::MouseButtonReleased { button } => {
             println!("Mouse Button released: {button:?}")
         }
-        Input::MouseScrolled { delta_x, delta_y } => {
-            println!("Mouse Scrolled: {delta_x} {delta_y}")
+        Input::MouseScrolled {
+            delta_x,
+            delta_y,
+            unit,
+            phase,
+        } => {
+            println!("Mouse Scrolled: {delta_x} {delta_y} ({unit:?}, {phase:?})")
         }
+        Input::TouchStarted { touch } => println!("Touch started: {touch:?}"),
+        Input::TouchMoved { touch } => println!("Touch moved: {touch:?}"),
+        Input::TouchEnded { touch } => println!("Touch ended: {touch:?}"),
+        Input::TouchCancelled { touch } => println!("Touch cancelled: {touch:?}"),
     }
 }
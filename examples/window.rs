@@ -25,7 +25,7 @@ fn main() {
                 WindowEvent::WindowReady(_, _) => {
                     canvas = Some({
                         let handle = window.handle().unwrap();
-                        let (width, height) = window.size();
+                        let (width, height) = window.size().into();
                         let surface_texture = SurfaceTexture::new(width, height, &handle);
                         let mut pixels = Pixels::new(width, height, surface_texture).unwrap();
                         pixels.clear_color(Color::RED);
@@ -43,8 +43,11 @@ fn main() {
                         pixels.resize_surface(*width, *height).unwrap();
                     }
                 }
-                WindowEvent::Input(uuid, input) => {
-                    println!("Input into window ({:?}): {:?}", uuid, input)
+                WindowEvent::Input(uuid, device_id, input) => {
+                    println!(
+                        "Input into window ({:?}) from device ({:?}): {:?}",
+                        uuid, device_id, input
+                    )
                 }
                 WindowEvent::WindowClosed(_) => context.exit(),
                 WindowEvent::Exited => println!("Goodbye, World!"),